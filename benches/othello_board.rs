@@ -1,5 +1,5 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use magpie::othello::{Bitboard, Board, Position, Stone};
+use magpie::othello::{Action, Bitboard, Board, Position, Stone};
 use std::hint::black_box;
 
 fn bench_clone(c: &mut Criterion) {
@@ -48,6 +48,41 @@ fn bench_hot_bits_extraction(c: &mut Criterion) {
     });
 }
 
+fn bench_hot_bits_extraction_lsb_first(c: &mut Criterion) {
+    let board = board_for_legal_moves();
+    let moves = board.moves_for(Stone::Black);
+    c.bench_function("hot_bits_extraction_lsb_first", |b| {
+        b.iter(|| moves.hot_bits_lsb_first().collect::<Vec<Position>>());
+    });
+}
+
+fn bench_pop_last(c: &mut Criterion) {
+    let board = board_for_legal_moves();
+    let moves = board.moves_for(Stone::Black);
+    c.bench_function("pop_last", |b| {
+        b.iter(|| {
+            let mut moves = black_box(moves);
+            let mut count = 0;
+            while moves.pop_last().is_some() {
+                count += 1;
+            }
+            count
+        });
+    });
+}
+
+fn bench_transcript_tokens(c: &mut Criterion) {
+    let transcript = "f5d6c3d3c4f4f6f3e6e7d7c6g5g6e3c5b5b4c7b6a5a4a3d8c8e8f7g4h4h3h5h6\
+                      g3f8g8h8h7g7f2e2d2c2c1d1e1b1b3a6a7b7a8b8g1f1g2h2h1b2a2a1";
+    c.bench_function("transcript_tokens", |b| {
+        b.iter(|| {
+            Action::tokens(black_box(transcript))
+                .collect::<Result<Vec<Action>, _>>()
+                .unwrap()
+        });
+    });
+}
+
 fn bench_perft(c: &mut Criterion) {
     let mut group = c.benchmark_group("perft");
 
@@ -68,6 +103,9 @@ criterion_group!(
     bench_legal_move_check,
     bench_bits_extraction,
     bench_hot_bits_extraction,
+    bench_hot_bits_extraction_lsb_first,
+    bench_pop_last,
+    bench_transcript_tokens,
     bench_perft
 );
 criterion_main!(benches);
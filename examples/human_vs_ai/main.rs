@@ -1,15 +1,15 @@
-use crate::agent::{Action, Agent, HumanAgent, RandomAgent};
-use magpie::othello::{Game, Status, Stone};
-
-pub mod agent;
+use magpie::{
+    agent::{Action, Agent, HumanAgent, RandomAgent},
+    othello::{Game, Score, Status, Stone},
+};
 
 fn main() {
     play();
 }
 
 fn play() {
-    let mut agent1 = HumanAgent;
-    let mut agent2 = RandomAgent;
+    let mut agent1 = HumanAgent::stdio();
+    let mut agent2 = RandomAgent::new();
 
     let mut game = Game::new();
 
@@ -17,25 +17,21 @@ fn play() {
         let current_turn = game.current_turn();
         println!("{}", game.display().with_stone(current_turn));
         let action = match current_turn {
-            Stone::Black => agent1.play(Stone::Black, game.board()),
-            Stone::White => agent2.play(Stone::White, game.board()),
+            Stone::Black => agent1.play(Stone::Black, &game.board()),
+            Stone::White => agent2.play(Stone::White, &game.board()),
         };
 
+        game.apply(action)
+            .unwrap_or_else(|_| panic!("{current_turn:?} tried to make an illegal move"));
         match action {
             Action::Move(next_move) => {
-                game.play(next_move)
-                    .unwrap_or_else(|_| panic!("{current_turn:?} tried to make an illegal move"));
                 println!("{current_turn:?} played {}", next_move.to_notation());
             }
-            Action::Pass => {
-                println!("{current_turn:?} passed their turn");
-                game.pass_turn();
-            }
+            Action::Pass => println!("{current_turn:?} passed their turn"),
         }
     }
     println!("Final board");
     println!("{}", game.display());
-    let black = game.bits_for(Stone::Black).count_set();
-    let white = game.bits_for(Stone::White).count_set();
+    let Score { black, white } = game.score();
     println!("Game finished with {black} - {white} ((B)lack - (W)hite)");
 }
@@ -1,13 +1,27 @@
 use magpie::othello::{Bitboard, Board, Game, Position, Stone};
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::fmt::Debug;
 
+// The default representations favor compactness. The notation adapters
+// represent positions, boards and games as human-readable text instead.
+#[derive(Debug, Serialize, Deserialize)]
+struct Readable {
+    #[serde(with = "magpie::serde::notation")]
+    game: Game,
+    #[serde(with = "magpie::serde::notation::option")]
+    last_move: Option<Position>,
+}
+
 fn main() -> Result<(), serde_json::Error> {
     print_serde(&Game::new())?;
     print_serde(&Board::standard())?;
     print_serde(&Stone::Black)?;
     print_serde(&Bitboard::FILLED)?;
     print_serde(&Position::try_from("A1").unwrap())?;
+    print_serde(&Readable {
+        game: Game::new(),
+        last_move: Position::try_from("e6").ok(),
+    })?;
 
     Ok(())
 }
@@ -0,0 +1,37 @@
+use crate::{
+    agent::Agent,
+    othello::{Action, Board, Stone},
+};
+
+/// Plays the move that flips the most stones. If no legal moves are
+/// available, passes their turn.
+///
+/// Ties are broken in favor of the move closest to A1.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, GreedyAgent};
+/// use magpie::othello::{Action, Board, Stone};
+///
+/// let board = Board::standard();
+/// let action = GreedyAgent.play(Stone::Black, &board);
+/// assert!(matches!(action, Action::Move(_)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct GreedyAgent;
+
+impl Agent for GreedyAgent {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        let before = board.bits_for(stone).count_set();
+        board
+            .moves_for(stone)
+            .hot_bits()
+            .max_by_key(|pos| {
+                let mut next = board.clone();
+                next.play(stone, *pos);
+                // Positions closer to A1 compare greater, so they win ties
+                (next.bits_for(stone).count_set() - before, *pos)
+            })
+            .map_or(Action::Pass, Action::Move)
+    }
+}
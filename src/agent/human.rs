@@ -0,0 +1,94 @@
+use crate::{
+    agent::Agent,
+    othello::{Action, Board, Stone},
+};
+use std::io::{self, BufRead, StdinLock, Stdout, Write};
+
+/// Queries a human to provide a valid move to play.
+///
+/// The human may only pass their turn, by entering `pass`, when no legal
+/// moves are available. Moves are read line by line from the input, while
+/// prompts and error messages are written to the output.
+///
+/// # Panics
+///
+/// Playing panics if reading from the input or writing to the output fails,
+/// or if the input is exhausted before a valid action was entered.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, HumanAgent};
+/// use magpie::othello::{Action, Board, Position, Stone};
+///
+/// let input = "z9\nd3\n".as_bytes();
+/// let mut agent = HumanAgent::new(input, Vec::new());
+///
+/// let action = agent.play(Stone::Black, &Board::standard());
+/// assert_eq!(action, Action::Move(Position::try_from("d3").unwrap()));
+/// ```
+#[derive(Debug)]
+pub struct HumanAgent<R = StdinLock<'static>, W = Stdout> {
+    input: R,
+    output: W,
+}
+
+impl HumanAgent {
+    /// Returns an agent reading from standard input and writing to standard
+    /// output.
+    #[must_use]
+    pub fn stdio() -> Self {
+        Self::new(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> HumanAgent<R, W> {
+    /// Returns an agent reading moves from `input` and writing prompts to
+    /// `output`.
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    /// Consumes the agent, returning the underlying input and output.
+    pub fn into_inner(self) -> (R, W) {
+        (self.input, self.output)
+    }
+
+    fn prompt(&mut self) -> io::Result<Option<Action>> {
+        write!(self.output, "> ")?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(line.trim().parse().ok())
+    }
+
+    fn read_action(&mut self, stone: Stone, board: &Board) -> io::Result<Action> {
+        let moves = board.moves_for(stone);
+        loop {
+            match self.prompt()? {
+                Some(Action::Pass) if moves.is_empty() => return Ok(Action::Pass),
+                Some(Action::Pass) => {
+                    writeln!(self.output, "Passing is only allowed without legal moves")?;
+                }
+                Some(Action::Move(pos)) if board.is_legal_move(stone, pos) => {
+                    return Ok(Action::Move(pos));
+                }
+                Some(Action::Move(pos)) => {
+                    writeln!(self.output, "\"{pos}\" is not a legal move")?;
+                }
+                None if moves.is_empty() => {
+                    writeln!(self.output, "No legal moves available, please \"pass\"")?;
+                }
+                None => writeln!(self.output, "Please enter a valid move, such as \"d3\"")?,
+            }
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> Agent for HumanAgent<R, W> {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        self.read_action(stone, board)
+            .expect("Failed to read action from human")
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    agent::Agent,
+    othello::{Action, Board, Stone},
+    search::Search,
+};
+
+/// Plays the best move found by a depth-limited alpha-beta [`Search`]. If no
+/// legal moves are available, passes their turn.
+///
+/// [`Search`]: crate::search::Search
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, MinimaxAgent};
+/// use magpie::othello::{Action, Board, Stone};
+///
+/// let mut agent = MinimaxAgent::new(3);
+/// let board = Board::standard();
+/// let action = agent.play(Stone::Black, &board);
+/// assert!(matches!(action, Action::Move(_)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct MinimaxAgent {
+    search: Search,
+}
+
+impl MinimaxAgent {
+    /// Returns an agent that searches `depth` moves ahead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::MinimaxAgent;
+    ///
+    /// let agent = MinimaxAgent::new(4);
+    /// assert_eq!(agent.search().depth(), 4);
+    /// ```
+    #[must_use]
+    pub fn new(depth: u8) -> Self {
+        Self::with_search(Search::new(depth))
+    }
+
+    /// Returns an agent that uses the specified search.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::MinimaxAgent;
+    /// use magpie::search::Search;
+    ///
+    /// let agent = MinimaxAgent::with_search(Search::new(4).with_endgame(14));
+    /// assert_eq!(agent.search().endgame(), 14);
+    /// ```
+    #[must_use]
+    pub fn with_search(search: Search) -> Self {
+        Self { search }
+    }
+
+    /// Returns the search used by the agent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::MinimaxAgent;
+    ///
+    /// assert_eq!(MinimaxAgent::new(2).search().depth(), 2);
+    /// ```
+    #[must_use]
+    pub fn search(&self) -> Search {
+        self.search
+    }
+}
+
+impl Agent for MinimaxAgent {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        self.search
+            .best_move(board, stone)
+            .map_or(Action::Pass, |evaluation| Action::Move(evaluation.position))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    agent::Agent,
+    othello::{Action, Board, Stone},
+};
+
+/// Plays the move that maximizes its own mobility relative to the opponent's.
+/// If no legal moves are available, passes their turn.
+///
+/// Mobility is the number of legal moves available to a player, and
+/// restricting the opponent's options is a classic Othello strategy.
+///
+/// Ties are broken in favor of the move closest to A1.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, MobilityAgent};
+/// use magpie::othello::{Action, Board, Stone};
+///
+/// let board = Board::standard();
+/// let action = MobilityAgent.play(Stone::Black, &board);
+/// assert!(matches!(action, Action::Move(_)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct MobilityAgent;
+
+impl Agent for MobilityAgent {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        board
+            .moves_for(stone)
+            .hot_bits()
+            .max_by_key(|pos| {
+                let mut next = board.clone();
+                next.play(stone, *pos);
+                let own = i32::from(next.moves_for(stone).count_set());
+                let opponent = i32::from(next.moves_for(stone.flip()).count_set());
+                // Positions closer to A1 compare greater, so they win ties
+                (own - opponent, *pos)
+            })
+            .map_or(Action::Pass, Action::Move)
+    }
+}
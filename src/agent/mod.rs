@@ -0,0 +1,61 @@
+use crate::othello::{Board, Stone};
+
+/// Maximizes the number of stones flipped.
+mod greedy;
+/// Reads moves from a human.
+mod human;
+/// Searches a fixed number of moves ahead.
+mod minimax;
+/// Minimizes the mobility of the opponent.
+mod mobility;
+/// Plays uniformly random legal moves.
+mod random;
+
+pub use crate::othello::Action;
+pub use greedy::GreedyAgent;
+pub use human::HumanAgent;
+pub use minimax::MinimaxAgent;
+pub use mobility::MobilityAgent;
+pub use random::RandomAgent;
+
+/// This trait defines an agent.
+///
+/// Given a board with a certain state and a stone, which represents the color
+/// of the agent, it needs to provide an action. The returned action is
+/// expected to be legal: a move the agent is allowed to make, or a pass if
+/// and only if the agent has no legal moves.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, GreedyAgent, RandomAgent};
+/// use magpie::othello::{Game, Status, Stone};
+///
+/// let mut black = GreedyAgent;
+/// let mut white = RandomAgent::with_seed(7);
+///
+/// let mut game = Game::new();
+/// while game.status() == Status::Progressing {
+///     let stone = game.current_turn();
+///     let action = match stone {
+///         Stone::Black => black.play(stone, &game.board()),
+///         Stone::White => white.play(stone, &game.board()),
+///     };
+///     game.apply(action).unwrap();
+/// }
+/// ```
+pub trait Agent {
+    /// Picks an action for the specified stone.
+    fn play(&mut self, stone: Stone, board: &Board) -> Action;
+}
+
+impl<A: Agent + ?Sized> Agent for Box<A> {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        (**self).play(stone, board)
+    }
+}
+
+impl<A: Agent + ?Sized> Agent for &mut A {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        (**self).play(stone, board)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    agent::Agent,
+    othello::{Action, Board, Stone},
+    rng::Rng,
+};
+
+/// Plays completely randomly. If no legal moves are available, passes their
+/// turn.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{Agent, RandomAgent};
+/// use magpie::othello::{Action, Board, Stone};
+///
+/// let mut agent = RandomAgent::new();
+/// let board = Board::standard();
+/// let Action::Move(pos) = agent.play(Stone::Black, &board) else {
+///     panic!("there are legal moves available");
+/// };
+/// assert!(board.is_legal_move(Stone::Black, pos));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RandomAgent {
+    rng: Rng,
+}
+
+impl RandomAgent {
+    /// Returns an agent seeded from a source of randomness.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rng: Rng::from_entropy(),
+        }
+    }
+
+    /// Returns an agent that always plays the same moves given the same seed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::{Agent, RandomAgent};
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let first = RandomAgent::with_seed(42).play(Stone::Black, &board);
+    /// let second = RandomAgent::with_seed(42).play(Stone::Black, &board);
+    /// assert_eq!(first, second);
+    /// ```
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Default for RandomAgent {
+    /// Returns an agent seeded from a source of randomness.
+    ///
+    /// Simply delegates to the [`new`] constructor.
+    ///
+    /// [`new`]: crate::agent::RandomAgent::new
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Agent for RandomAgent {
+    fn play(&mut self, stone: Stone, board: &Board) -> Action {
+        self.rng
+            .choose(board.moves_for(stone))
+            .map_or(Action::Pass, Action::Move)
+    }
+}
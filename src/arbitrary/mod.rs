@@ -0,0 +1,43 @@
+use crate::{othello::Game, rng::Rng};
+
+/// Implements `quickcheck::Arbitrary` for the core types.
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+/// Provides proptest strategies and implements `proptest::arbitrary::Arbitrary`.
+#[cfg(feature = "proptest")]
+mod strategy;
+
+#[cfg(feature = "proptest")]
+pub use strategy::{bitboard, board, position, reachable_board, reachable_game, stone};
+
+/// Plays up to the specified number of random legal moves from the standard
+/// opening, passing whenever the player to move has no legal moves.
+///
+/// The playout stops early if the game ends. The same seed and number of
+/// moves always result in the same game, which makes the playout suitable for
+/// generating reachable positions in property tests with any framework.
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::playout;
+///
+/// let game = playout(42, 10);
+/// assert_eq!(64 - game.empty_squares().count_set(), 14);
+/// assert_eq!(game, playout(42, 10));
+/// ```
+#[must_use]
+pub fn playout(seed: u64, moves: u8) -> Game {
+    let mut rng = Rng::new(seed);
+    let mut game = Game::new();
+    let mut played = 0;
+    while played < moves && !game.is_over() {
+        match rng.choose(game.moves()) {
+            Some(pos) => {
+                game.play(pos).expect("random moves are legal");
+                played += 1;
+            }
+            None => game.pass().expect("players without moves may pass"),
+        }
+    }
+    game
+}
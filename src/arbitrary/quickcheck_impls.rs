@@ -0,0 +1,60 @@
+use crate::{
+    arbitrary::playout,
+    othello::{Bitboard, Board, Direction, Game, Position, Square, Stone},
+};
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for Stone {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Stone::Black
+        } else {
+            Stone::White
+        }
+    }
+}
+
+impl Arbitrary for Bitboard {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Bitboard::from(u64::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.raw().shrink().map(Bitboard::from))
+    }
+}
+
+impl Arbitrary for Position {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&Position::ALL).expect("there are 64 positions")
+    }
+}
+
+impl Arbitrary for Square {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&Square::ALL).expect("there are 64 squares")
+    }
+}
+
+impl Arbitrary for Direction {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&Direction::ALL)
+            .expect("there are eight directions")
+    }
+}
+
+impl Arbitrary for Board {
+    /// Generates a board reachable from the standard opening, by a random
+    /// playout of up to 60 moves.
+    fn arbitrary(g: &mut Gen) -> Self {
+        Game::arbitrary(g).board()
+    }
+}
+
+impl Arbitrary for Game {
+    /// Generates a game reachable from the standard opening, by a random
+    /// playout of up to 60 moves.
+    fn arbitrary(g: &mut Gen) -> Self {
+        playout(u64::arbitrary(g), u8::arbitrary(g) % 61)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    arbitrary::playout,
+    othello::{Bitboard, Board, Game, Position, Stone},
+};
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+/// Returns a strategy of bitboards with any bits set.
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::bitboard;
+/// use proptest::proptest;
+///
+/// proptest!(|(b in bitboard())| {
+///     assert_eq!(b.count_set() + b.count_empty(), 64);
+/// });
+/// ```
+pub fn bitboard() -> impl Strategy<Value = Bitboard> {
+    any::<u64>().prop_map(Bitboard::from)
+}
+
+/// Returns a strategy of positions anywhere on the board.
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::position;
+/// use proptest::proptest;
+///
+/// proptest!(|(pos in position())| {
+///     assert_eq!(pos.raw().count_ones(), 1);
+/// });
+/// ```
+pub fn position() -> impl Strategy<Value = Position> {
+    (0..Position::ALL.len()).prop_map(|index| Position::ALL[index])
+}
+
+/// Returns a strategy of black and white stones.
+pub fn stone() -> impl Strategy<Value = Stone> {
+    prop_oneof![Just(Stone::Black), Just(Stone::White)]
+}
+
+/// Returns a strategy of valid boards with stones placed anywhere, most of
+/// which can never occur in a game.
+///
+/// See [`reachable_board`] for boards that can occur in games.
+///
+/// [`reachable_board`]: crate::arbitrary::reachable_board
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::board;
+/// use proptest::proptest;
+///
+/// proptest!(|(board in board())| {
+///     assert!(board.is_valid());
+/// });
+/// ```
+pub fn board() -> impl Strategy<Value = Board> {
+    (any::<u64>(), any::<u64>()).prop_map(|(black, white)| {
+        Board::try_from((black, white & !black)).expect("stones never overlap")
+    })
+}
+
+/// Returns a strategy of boards reachable from the standard opening, by a
+/// random playout of up to 60 moves.
+///
+/// Boards shrink towards fewer moves played, and thus towards the standard
+/// opening.
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::reachable_board;
+/// use magpie::othello::Bitboard;
+/// use proptest::proptest;
+///
+/// proptest!(|(board in reachable_board())| {
+///     // The center squares can never be emptied
+///     assert_eq!(board.empty_squares() & Bitboard::CENTER, 0);
+/// });
+/// ```
+pub fn reachable_board() -> impl Strategy<Value = Board> {
+    reachable_game().prop_map(|game| game.board())
+}
+
+/// Returns a strategy of games reachable from the standard opening, by a
+/// random playout of up to 60 moves.
+///
+/// Games shrink towards fewer moves played, and thus towards the standard
+/// opening.
+///
+/// # Examples
+/// ```rust
+/// use magpie::arbitrary::reachable_game;
+/// use proptest::proptest;
+///
+/// proptest!(|(game in reachable_game())| {
+///     assert!(game.board().is_valid());
+/// });
+/// ```
+pub fn reachable_game() -> impl Strategy<Value = Game> {
+    (any::<u64>(), 0..=60u8).prop_map(|(seed, moves)| playout(seed, moves))
+}
+
+impl Arbitrary for Bitboard {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        bitboard().boxed()
+    }
+}
+
+impl Arbitrary for Position {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        position().boxed()
+    }
+}
+
+impl Arbitrary for Stone {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        stone().boxed()
+    }
+}
+
+impl Arbitrary for Board {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates boards reachable from the standard opening.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        reachable_board().boxed()
+    }
+}
+
+impl Arbitrary for Game {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Generates games reachable from the standard opening.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        reachable_game().boxed()
+    }
+}
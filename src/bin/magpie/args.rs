@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+/// Command line arguments split into positional arguments and options.
+///
+/// Options are written as `--name value`, or just `--name` for flags. Only
+/// the options a command declares are accepted.
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    /// Parses the arguments, given the names of the options that take a value
+    /// and of the flags that do not.
+    pub fn parse<I>(args: I, valued: &[&str], flags: &[&str]) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut result = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = if valued.contains(&name) {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{name}"))?;
+                    Some(value)
+                } else if flags.contains(&name) {
+                    None
+                } else {
+                    return Err(format!("unknown option --{name}"));
+                };
+                result.options.insert(name.to_string(), value);
+            } else {
+                result.positional.push(arg);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the positional argument at the specified index.
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    /// Returns the value of the specified option.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|value| value.as_deref())
+    }
+
+    /// Returns whether the specified flag was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Parses the value of the specified option, falling back to a default.
+    pub fn parsed<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.value(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid value for --{name}: \"{value}\"")),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use magpie::{
+    ggf::{GgfError, GgfGame, GgfMove},
+    othello::{Bitboard, Board, Game, Position, Stone},
+};
+use std::{
+    error::Error,
+    io::{self, Read},
+    str::FromStr,
+};
+
+/// The textual formats games can be read from and written to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Concatenated moves from the standard opening, e.g. `f5d6c3`.
+    Transcript,
+    /// A Generic Game Format record.
+    Ggf,
+    /// 64 squares from A1 to H8 followed by the player to move, using `X`
+    /// for black, `O` for white and `-` for empty squares.
+    Board,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "transcript" => Ok(Format::Transcript),
+            "ggf" => Ok(Format::Ggf),
+            "board" => Ok(Format::Board),
+            _ => Err(format!("unknown format \"{text}\"")),
+        }
+    }
+}
+
+/// Reads the argument, or standard input if the argument is `-`.
+pub fn read_input(arg: &str) -> io::Result<String> {
+    if arg == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+/// Parses a game in any of the supported formats, detecting which one is
+/// used.
+pub fn parse_game(text: &str) -> Result<GgfGame, Box<dyn Error>> {
+    let text = text.trim();
+    if text.starts_with("(;") {
+        Ok(text.parse()?)
+    } else if is_board(text) {
+        parse_board(text)
+    } else {
+        parse_transcript(text)
+    }
+}
+
+/// Formats the game in the specified format.
+///
+/// Transcripts can only describe games played from the standard opening, and
+/// boards only describe the final position.
+pub fn format_game(record: &GgfGame, format: Format) -> Result<String, Box<dyn Error>> {
+    match format {
+        Format::Ggf => Ok(record.to_string()),
+        Format::Board => Ok(format_board(&record.to_game()?)),
+        Format::Transcript => {
+            if record.board() != Board::standard() || record.first_player() != Stone::Black {
+                return Err("only games from the standard opening have transcripts".into());
+            }
+            Ok(record
+                .moves()
+                .iter()
+                .filter_map(GgfMove::position)
+                .map(Position::to_notation)
+                .collect())
+        }
+    }
+}
+
+fn is_board(text: &str) -> bool {
+    let squares: String = text.split_whitespace().collect();
+    squares.len() == 65 && squares.chars().all(|c| "XOxo*-.".contains(c))
+}
+
+fn parse_board(text: &str) -> Result<GgfGame, Box<dyn Error>> {
+    let squares: Vec<char> = text.split_whitespace().flat_map(str::chars).collect();
+    let mut board = Board::empty();
+    for (pos, c) in Bitboard::FILLED.hot_bits().zip(&squares) {
+        match c {
+            'X' | 'x' | '*' => board.place_stone_unchecked(Stone::Black, pos.into()),
+            'O' | 'o' => board.place_stone_unchecked(Stone::White, pos.into()),
+            _ => {}
+        }
+    }
+    let stone = match squares[64] {
+        'X' | 'x' | '*' => Stone::Black,
+        'O' | 'o' => Stone::White,
+        c => return Err(format!("invalid player to move \"{c}\"").into()),
+    };
+    Ok(GgfGame::new(board, stone))
+}
+
+fn parse_transcript(text: &str) -> Result<GgfGame, Box<dyn Error>> {
+    GgfGame::from_transcript(text).map_err(|e| match e {
+        GgfError::IllegalMove(_) => "illegal move in transcript".into(),
+        e => e.into(),
+    })
+}
+
+fn format_board(game: &Game) -> String {
+    let mut text: String = Bitboard::FILLED
+        .hot_bits()
+        .map(|pos| match game.stone_at(pos) {
+            Some(Stone::Black) => 'X',
+            Some(Stone::White) => 'O',
+            None => '-',
+        })
+        .collect();
+    text.push_str(match game.current_turn() {
+        Stone::Black => " X",
+        Stone::White => " O",
+    });
+    text
+}
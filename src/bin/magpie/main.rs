@@ -0,0 +1,253 @@
+use crate::{
+    args::Args,
+    formats::Format,
+    play::{Player, name},
+};
+use magpie::{
+    ggf::GgfGame,
+    othello::{Board, Format as DisplayFormat, Game, Score, Stone},
+    search::{self, Search},
+};
+use std::{error::Error, process::ExitCode, time::Instant};
+
+mod args;
+mod formats;
+mod play;
+
+/// The most empty squares `solve` accepts. Every additional empty square
+/// multiplies the time a perfect solve takes several times over.
+const MAX_SOLVE_EMPTIES: u8 = 24;
+
+const USAGE: &str = "\
+Usage: magpie <command> [options]
+
+Games and positions can be given as a transcript (f5d6c3), a GGF record or a
+board string (64 squares of X, O and - followed by the player to move). Use -
+to read them from standard input.
+
+Commands:
+  play [--black <player>] [--white <player>] [--from <game>]
+      Plays a game. Players are human, random, greedy, mobility, engine or
+      engine:<depth>.
+  analyze <game> [--depth <n>]
+      Compares every move of a game with the engine's choice.
+  solve <game>
+      Solves the final position of a game perfectly. The position may have
+      at most 24 empty squares.
+  perft <depth> [<game>]
+      Counts the leaf nodes reachable in <depth> plies.
+  convert <game> --to <transcript|ggf|board>
+      Converts a game between formats.
+  show [<game>] [--compact | --unicode | --ansi] [--moves | --numbers]
+      Displays the final position of a game, or the number of the move
+      played on every square.
+";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let command = args.next();
+    let result = match command.as_deref() {
+        Some("play") => run(args, &["black", "white", "from"], &[], cmd_play),
+        Some("analyze") => run(args, &["depth"], &[], cmd_analyze),
+        Some("solve") => run(args, &[], &[], cmd_solve),
+        Some("perft") => run(args, &[], &[], cmd_perft),
+        Some("convert") => run(args, &["to"], &[], cmd_convert),
+        Some("show") => run(
+            args,
+            &[],
+            &["compact", "unicode", "ansi", "moves", "numbers"],
+            cmd_show,
+        ),
+        Some("help" | "--help" | "-h") => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command \"{command}\"\n\n{USAGE}").into()),
+        None => Err(USAGE.into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the arguments of a command and runs it, rejecting options the
+/// command does not know.
+fn run(
+    args: impl Iterator<Item = String>,
+    valued: &[&str],
+    flags: &[&str],
+    command: fn(&Args) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(args, valued, flags).map_err(|e| format!("{e}\n\n{USAGE}"))?;
+    command(&args)
+}
+
+fn game_arg(args: &Args, index: usize) -> Result<GgfGame, Box<dyn Error>> {
+    match args.positional(index) {
+        Some(arg) => formats::parse_game(&formats::read_input(arg)?),
+        None => Ok(GgfGame::new(Board::standard(), Stone::Black)),
+    }
+}
+
+fn required_game_arg(args: &Args, index: usize) -> Result<GgfGame, Box<dyn Error>> {
+    if args.positional(index).is_none() {
+        return Err("missing game argument".into());
+    }
+    game_arg(args, index)
+}
+
+fn cmd_play(args: &Args) -> Result<(), Box<dyn Error>> {
+    let black: Player = args.parsed("black", Player::Human)?;
+    let white: Player = args.parsed("white", Player::Engine(Search::default()))?;
+    let record = match args.value("from") {
+        Some(from) => formats::parse_game(&formats::read_input(from)?)?,
+        None => GgfGame::new(Board::standard(), Stone::Black),
+    };
+    let record = play::play(black, white, record)?;
+    println!("{}", record);
+    Ok(())
+}
+
+fn cmd_analyze(args: &Args) -> Result<(), Box<dyn Error>> {
+    let record = required_game_arg(args, 0)?;
+    let search = Search::new(args.parsed("depth", Search::default().depth())?);
+
+    println!(
+        "{:>3}  {:<6} {:<5} {:>7}  {:<5} {:>7}  {:>6}",
+        "#", "player", "move", "eval", "best", "eval", "loss"
+    );
+    let mut game = Game::from_state(record.board(), record.first_player(), false)?;
+    for (number, mv) in (1..).zip(record.moves()) {
+        if mv.stone() != game.current_turn() {
+            // A record may leave out forced passes, but nothing else
+            game.pass().map_err(|_| {
+                format!(
+                    "{} played move {number}, but {} is to move",
+                    name(mv.stone()),
+                    name(game.current_turn())
+                )
+            })?;
+        }
+        let Some(pos) = mv.position() else {
+            println!("{number:>3}  {:<6} {:<5}", name(mv.stone()), "pass");
+            game.pass()
+                .map_err(|_| format!("illegal pass at move {number}"))?;
+            continue;
+        };
+        let evaluations = search.evaluate_moves(&game.board(), game.current_turn());
+        let played = evaluations
+            .iter()
+            .find(|evaluation| evaluation.position == pos)
+            .ok_or_else(|| format!("illegal move {pos} at move {number}"))?;
+        let best = evaluations[0];
+        println!(
+            "{number:>3}  {:<6} {:<5} {:>7.2}  {:<5} {:>7.2}  {:>6.2}",
+            name(mv.stone()),
+            pos,
+            discs(played.score),
+            best.position,
+            discs(best.score),
+            discs(best.score - played.score),
+        );
+        game.play(pos)?;
+    }
+    Ok(())
+}
+
+fn cmd_solve(args: &Args) -> Result<(), Box<dyn Error>> {
+    let record = required_game_arg(args, 0)?;
+    let game = record.to_game()?;
+    let board = game.board();
+    let empties = board.empty_squares().count_set();
+    if empties > MAX_SOLVE_EMPTIES {
+        return Err(format!(
+            "the position has {empties} empty squares, solve accepts at most {MAX_SOLVE_EMPTIES}"
+        )
+        .into());
+    }
+    let mut stone = game.current_turn();
+    if board.moves_for(stone).is_empty() {
+        if board.moves_for(stone.flip()).is_empty() {
+            let margin = search::solve(&board, stone);
+            println!(
+                "The game is over, {} finishes with a margin of {margin:+}",
+                name(stone)
+            );
+            return Ok(());
+        }
+        println!("{} has to pass", name(stone));
+        stone = stone.flip();
+    }
+
+    let start = Instant::now();
+    let solver = Search::new(0).with_endgame(64);
+    let best = solver
+        .best_move(&board, stone)
+        .expect("there is at least one legal move");
+    println!(
+        "{} plays {} and finishes with a margin of {:+} ({:.2?})",
+        name(stone),
+        best.position,
+        best.score / 100,
+        start.elapsed()
+    );
+    Ok(())
+}
+
+fn cmd_perft(args: &Args) -> Result<(), Box<dyn Error>> {
+    let depth: u8 = args
+        .positional(0)
+        .ok_or("missing depth argument")?
+        .parse()
+        .map_err(|_| "invalid depth")?;
+    let game = game_arg(args, 1)?.to_game()?;
+    for depth in 1..=depth {
+        let start = Instant::now();
+        let nodes = search::perft(&game.board(), game.current_turn(), depth);
+        println!("depth {depth:>2}: {nodes:>14} ({:.2?})", start.elapsed());
+    }
+    Ok(())
+}
+
+fn cmd_convert(args: &Args) -> Result<(), Box<dyn Error>> {
+    let record = required_game_arg(args, 0)?;
+    let format: Format = args.value("to").ok_or("missing --to option")?.parse()?;
+    println!("{}", formats::format_game(&record, format)?);
+    Ok(())
+}
+
+fn cmd_show(args: &Args) -> Result<(), Box<dyn Error>> {
+    let record = game_arg(args, 0)?;
+    let game = record.to_game()?;
+    let format = if args.flag("compact") {
+        DisplayFormat::Compact
+    } else if args.flag("unicode") {
+        DisplayFormat::Unicode
+    } else if args.flag("ansi") {
+        DisplayFormat::Ansi
+    } else {
+        DisplayFormat::Standard
+    };
+    let display = game.display().with_format(format);
+    if args.flag("numbers") {
+        print!("{}", record.move_diagram().with_format(format));
+    } else if args.flag("moves") {
+        print!("{}", display.with_stone(game.current_turn()));
+    } else {
+        print!("{display}");
+    }
+    let Score { black, white } = game.score();
+    println!(
+        "Black {black} - {white} White, {} to move",
+        name(game.current_turn())
+    );
+    Ok(())
+}
+
+fn discs(score: i32) -> f64 {
+    f64::from(score) / 100.0
+}
@@ -0,0 +1,133 @@
+use magpie::{
+    agent::{Action, Agent, GreedyAgent, MinimaxAgent, MobilityAgent, RandomAgent},
+    ggf::{GgfGame, GgfMove},
+    othello::{Game, Position, Score, Status, Stone},
+    search::Search,
+};
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+/// A participant in a game started with `magpie play`.
+#[derive(Clone, Copy, Debug)]
+pub enum Player {
+    /// Moves are read from standard input.
+    Human,
+    /// Moves are picked uniformly at random.
+    Random,
+    /// Moves that flip the most stones are picked.
+    Greedy,
+    /// Moves that restrict the opponent's mobility are picked.
+    Mobility,
+    /// Moves are picked by an alpha-beta search.
+    Engine(Search),
+}
+
+impl Player {
+    /// Returns the agent picking moves for the player, or `None` for humans.
+    fn agent(self) -> Option<Box<dyn Agent>> {
+        match self {
+            Player::Human => None,
+            Player::Random => Some(Box::new(RandomAgent::new())),
+            Player::Greedy => Some(Box::new(GreedyAgent)),
+            Player::Mobility => Some(Box::new(MobilityAgent)),
+            Player::Engine(search) => Some(Box::new(MinimaxAgent::with_search(search))),
+        }
+    }
+}
+
+impl FromStr for Player {
+    type Err = String;
+
+    /// Parses `human`, `random`, `greedy`, `mobility`, `engine` or
+    /// `engine:<depth>`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.split_once(':') {
+            None if text == "human" => Ok(Player::Human),
+            None if text == "random" => Ok(Player::Random),
+            None if text == "greedy" => Ok(Player::Greedy),
+            None if text == "mobility" => Ok(Player::Mobility),
+            None if text == "engine" => Ok(Player::Engine(Search::default())),
+            Some(("engine", depth)) => depth
+                .parse()
+                .map(|depth| Player::Engine(Search::new(depth)))
+                .map_err(|_| format!("invalid engine depth \"{depth}\"")),
+            _ => Err(format!("unknown player \"{text}\"")),
+        }
+    }
+}
+
+/// Plays a game between the two players, starting from the record.
+///
+/// Returns the record extended with the moves that were played.
+pub fn play(black: Player, white: Player, mut record: GgfGame) -> Result<GgfGame, Box<dyn Error>> {
+    let mut game = record.to_game()?;
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut agents = [black.agent(), white.agent()];
+
+    while game.status() == Status::Progressing {
+        let stone = game.current_turn();
+        println!("{}", game.display().with_stone(stone));
+
+        if game.moves().is_empty() {
+            println!("{} has no legal moves and passes", name(stone));
+            record.push_move(GgfMove::pass(stone));
+            game.pass_turn();
+            continue;
+        }
+
+        let agent = match stone {
+            Stone::Black => &mut agents[0],
+            Stone::White => &mut agents[1],
+        };
+        let pos = match agent {
+            None => read_move(&mut input, &game)?,
+            Some(agent) => match agent.play(stone, &game.board()) {
+                Action::Move(pos) => pos,
+                Action::Pass => unreachable!("there is at least one legal move"),
+            },
+        };
+        game.play(pos)?;
+        record.push_move(GgfMove::new(stone, pos));
+        println!("{} played {pos}", name(stone));
+    }
+
+    println!("{}", game.display());
+    let Score { black, white } = game.score();
+    match game.status() {
+        Status::Win(stone) => println!("{} wins {black}-{white}", name(stone)),
+        _ => println!("Draw {black}-{white}"),
+    }
+    Ok(record)
+}
+
+fn read_move<R: BufRead>(input: &mut R, game: &Game) -> Result<Position, Box<dyn Error>> {
+    loop {
+        print!("{} to move > ", name(game.current_turn()));
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Err("input closed".into());
+        }
+        let line = line.trim();
+        if line == "quit" {
+            return Err("game aborted".into());
+        }
+        match line.parse::<Position>() {
+            Ok(pos) if game.is_legal_move(pos) => return Ok(pos),
+            Ok(_) => println!("\"{line}\" is not a legal move"),
+            Err(_) => println!("Please enter a move such as \"d3\", or \"quit\""),
+        }
+    }
+}
+
+pub fn name(stone: Stone) -> &'static str {
+    match stone {
+        Stone::Black => "Black",
+        Stone::White => "White",
+    }
+}
@@ -0,0 +1,8 @@
+use magpie::nboard::{SearchEngine, Session};
+use std::io;
+
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    Session::new(SearchEngine::default()).run(stdin.lock(), stdout.lock())
+}
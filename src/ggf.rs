@@ -0,0 +1,650 @@
+use crate::othello::{Action, Bitboard, Board, Game, GameError, MoveDiagram, Position, Stone};
+use std::{error, fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A game record in the Generic Game Format (GGF).
+///
+/// GGF is the format used by NBoard and most Othello servers to exchange
+/// games. A record consists of a list of tags, a starting position and the
+/// moves played from that position, for example:
+///
+/// ```text
+/// (;GM[Othello]PB[alice]PW[bob]BO[8 ---------------------------O*------*O--------------------------- *]B[F5]W[F6];)
+/// ```
+///
+/// Only 8x8 boards are supported.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggf::GgfGame;
+/// use magpie::othello::{Game, Stone};
+///
+/// let text = "(;GM[Othello]BO[8 ---------------------------O*------*O--------------------------- *]B[F5]W[F6];)";
+/// let record: GgfGame = text.parse().unwrap();
+/// assert_eq!(record.moves().len(), 2);
+///
+/// let game = record.to_game().unwrap();
+/// assert_eq!(game.current_turn(), Stone::Black);
+/// assert_eq!(record.to_string(), text);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GgfGame {
+    tags: Vec<(String, String)>,
+    board: Board,
+    first_player: Stone,
+    moves: Vec<GgfMove>,
+}
+
+/// A single move in a [`GgfGame`].
+///
+/// Besides the move itself, GGF moves may carry an evaluation and the time
+/// spent thinking, e.g. `B[F5/1.25/3.1]`.
+///
+/// [`GgfGame`]: crate::ggf::GgfGame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GgfMove {
+    stone: Stone,
+    position: Option<Position>,
+    eval: Option<f64>,
+    time: Option<f64>,
+}
+
+/// This enum represents errors that may occur when handling GGF records.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum GgfError {
+    /// Indicates that the text is not a well-formed GGF record.
+    InvalidSyntax,
+    /// Indicates that the `BO` tag does not describe a valid 8x8 board.
+    InvalidBoard,
+    /// Indicates that a move could not be parsed.
+    InvalidMove(String),
+    /// Indicates that a recorded move could not be played.
+    IllegalMove(GameError),
+}
+
+impl From<GameError> for GgfError {
+    fn from(e: GameError) -> Self {
+        GgfError::IllegalMove(e)
+    }
+}
+
+impl fmt::Display for GgfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => write!(f, "invalid GGF syntax"),
+            Self::InvalidBoard => write!(f, "invalid GGF board"),
+            Self::InvalidMove(text) => write!(f, "invalid GGF move \"{text}\""),
+            Self::IllegalMove(_) => write!(f, "illegal move in GGF record"),
+        }
+    }
+}
+
+impl error::Error for GgfError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::IllegalMove(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl GgfGame {
+    /// Returns an empty record starting from the specified board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let record = GgfGame::new(Board::standard(), Stone::Black);
+    /// assert_eq!(record.tag("GM"), Some("Othello"));
+    /// assert!(record.moves().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(board: Board, first_player: Stone) -> Self {
+        Self {
+            tags: vec![("GM".to_string(), "Othello".to_string())],
+            board,
+            first_player,
+            moves: Vec::new(),
+        }
+    }
+
+    /// Returns the board the game started from.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let record = GgfGame::new(Board::standard(), Stone::Black);
+    /// assert_eq!(record.board(), Board::standard());
+    /// ```
+    #[must_use]
+    pub fn board(&self) -> Board {
+        self.board.clone()
+    }
+
+    /// Returns the player who moved first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let record = GgfGame::new(Board::standard(), Stone::White);
+    /// assert_eq!(record.first_player(), Stone::White);
+    /// ```
+    #[must_use]
+    pub fn first_player(&self) -> Stone {
+        self.first_player
+    }
+
+    /// Returns the moves played, in order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::{GgfGame, GgfMove};
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut record = GgfGame::new(Board::standard(), Stone::Black);
+    /// record.push_move(GgfMove::pass(Stone::Black));
+    /// assert_eq!(record.moves(), &[GgfMove::pass(Stone::Black)]);
+    /// ```
+    #[must_use]
+    pub fn moves(&self) -> &[GgfMove] {
+        &self.moves
+    }
+
+    /// Appends a move to the record.
+    ///
+    /// No validation is performed until the record is replayed with
+    /// [`to_game`].
+    ///
+    /// [`to_game`]: crate::ggf::GgfGame::to_game
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::{GgfGame, GgfMove};
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let mut record = GgfGame::new(Board::standard(), Stone::Black);
+    /// let pos = Position::try_from("F5").unwrap();
+    /// record.push_move(GgfMove::new(Stone::Black, pos));
+    /// assert_eq!(record.moves().len(), 1);
+    /// ```
+    pub fn push_move(&mut self, mv: GgfMove) {
+        self.moves.push(mv);
+    }
+
+    /// Returns the value of the specified tag, if present.
+    ///
+    /// The board (`BO`) and the moves (`B` and `W`) are not considered tags.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let text = "(;GM[Othello]PB[alice]BO[8 ---------------------------O*------*O--------------------------- *];)";
+    /// let record: GgfGame = text.parse().unwrap();
+    /// assert_eq!(record.tag("PB"), Some("alice"));
+    /// assert_eq!(record.tag("PW"), None);
+    /// ```
+    #[must_use]
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets the value of the specified tag, replacing any previous value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut record = GgfGame::new(Board::standard(), Stone::Black);
+    /// record.set_tag("PB", "alice");
+    /// assert_eq!(record.tag("PB"), Some("alice"));
+    /// ```
+    pub fn set_tag(&mut self, key: &str, value: &str) {
+        match self.tags.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.tags.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Replays the record and returns the resulting game.
+    ///
+    /// Passes may be omitted from the record, in which case they are inserted
+    /// whenever a player moves out of turn while their opponent has no legal
+    /// moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::Stone;
+    ///
+    /// let text = "(;GM[Othello]BO[8 ---------------------------O*------*O--------------------------- *]B[F5];)";
+    /// let record: GgfGame = text.parse().unwrap();
+    /// let game = record.to_game().unwrap();
+    /// assert_eq!(game.current_turn(), Stone::White);
+    /// ```
+    pub fn to_game(&self) -> Result<Game, GgfError> {
+        let mut game = Game::from_state(self.board(), self.first_player, false)?;
+        for mv in &self.moves {
+            if mv.stone != game.current_turn() && game.moves().is_empty() {
+                game.pass_turn();
+            }
+            if mv.stone != game.current_turn() {
+                return Err(GameError::IllegalMove.into());
+            }
+            match mv.position {
+                Some(pos) => game.play(pos)?,
+                None => game.pass_turn(),
+            }
+        }
+        Ok(game)
+    }
+    /// Returns the "numbered moves" diagram of the record, where every
+    /// square shows the number of the move that placed a stone on it.
+    ///
+    /// Passes are not numbered and the moves are not validated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::othello::Position;
+    ///
+    /// let record = GgfGame::from_transcript("f5d6c3").unwrap();
+    /// let diagram = record.move_diagram();
+    /// assert_eq!(diagram.number_at(Position::try_from("c3").unwrap()), Some(3));
+    /// ```
+    #[must_use]
+    pub fn move_diagram(&self) -> MoveDiagram {
+        MoveDiagram::new(&self.board, self.moves.iter().filter_map(GgfMove::position))
+    }
+
+    /// Parses a transcript of a game from the standard opening, such as
+    /// `f5d6c3d3`.
+    ///
+    /// Every move is written as two characters, with `PA`, `pass` or `--`
+    /// denoting a pass, as parsed by [`Action::tokens`]. Whitespace between
+    /// moves is ignored and passes may be omitted, in which case they are
+    /// inserted whenever the player to move has no legal moves.
+    ///
+    /// [`Action::tokens`]: crate::othello::Action::tokens
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::{GgfError, GgfGame};
+    ///
+    /// let record = GgfGame::from_transcript("f5 d6 c3 d3").unwrap();
+    /// assert_eq!(record.moves().len(), 4);
+    ///
+    /// let error = GgfGame::from_transcript("f5z9");
+    /// assert_eq!(error, Err(GgfError::InvalidMove("z9".to_string())));
+    /// ```
+    pub fn from_transcript(text: &str) -> Result<Self, GgfError> {
+        let mut record = Self::new(Board::standard(), Stone::Black);
+        let mut game = Game::new();
+        let mut tokens = Action::tokens(text);
+        while let Some(token) = tokens.next() {
+            let Ok(action) = token else {
+                let token = tokens.remainder().chars().take(2).collect();
+                return Err(GgfError::InvalidMove(token));
+            };
+            let pos = match action {
+                Action::Pass => {
+                    record.push_move(GgfMove::pass(game.current_turn()));
+                    game.pass_turn();
+                    continue;
+                }
+                Action::Move(pos) => pos,
+            };
+            if game.moves().is_empty() {
+                record.push_move(GgfMove::pass(game.current_turn()));
+                game.pass_turn();
+            }
+            let stone = game.current_turn();
+            game.play(pos)?;
+            record.push_move(GgfMove::new(stone, pos));
+        }
+        Ok(record)
+    }
+}
+
+impl GgfMove {
+    /// Returns a move placing a stone at the specified position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::{Position, Stone};
+    ///
+    /// let pos = Position::try_from("F5").unwrap();
+    /// let mv = GgfMove::new(Stone::Black, pos);
+    /// assert_eq!(mv.position(), Some(pos));
+    /// ```
+    #[must_use]
+    pub fn new(stone: Stone, position: Position) -> Self {
+        Self {
+            stone,
+            position: Some(position),
+            eval: None,
+            time: None,
+        }
+    }
+
+    /// Returns a move representing a pass.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// let mv = GgfMove::pass(Stone::White);
+    /// assert_eq!(mv.position(), None);
+    /// assert_eq!(mv.to_string(), "W[PA]");
+    /// ```
+    #[must_use]
+    pub fn pass(stone: Stone) -> Self {
+        Self {
+            stone,
+            position: None,
+            eval: None,
+            time: None,
+        }
+    }
+
+    /// Returns the move annotated with an evaluation, in discs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::{Position, Stone};
+    ///
+    /// let pos = Position::try_from("F5").unwrap();
+    /// let mv = GgfMove::new(Stone::Black, pos).with_eval(1.5);
+    /// assert_eq!(mv.eval(), Some(1.5));
+    /// assert_eq!(mv.to_string(), "B[F5/1.50]");
+    /// ```
+    #[must_use]
+    pub fn with_eval(self, eval: f64) -> Self {
+        Self {
+            eval: Some(eval),
+            ..self
+        }
+    }
+
+    /// Returns the move annotated with the time spent, in seconds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::{Position, Stone};
+    ///
+    /// let pos = Position::try_from("F5").unwrap();
+    /// let mv = GgfMove::new(Stone::Black, pos).with_time(2.0);
+    /// assert_eq!(mv.time(), Some(2.0));
+    /// assert_eq!(mv.to_string(), "B[F5//2.00]");
+    /// ```
+    #[must_use]
+    pub fn with_time(self, time: f64) -> Self {
+        Self {
+            time: Some(time),
+            ..self
+        }
+    }
+
+    /// Returns the player who made the move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!(GgfMove::pass(Stone::White).stone(), Stone::White);
+    /// ```
+    #[must_use]
+    pub fn stone(&self) -> Stone {
+        self.stone
+    }
+
+    /// Returns the position played, or `None` if the move is a pass.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!(GgfMove::pass(Stone::White).position(), None);
+    /// ```
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the evaluation attached to the move, if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!(GgfMove::pass(Stone::White).eval(), None);
+    /// ```
+    #[must_use]
+    pub fn eval(&self) -> Option<f64> {
+        self.eval
+    }
+
+    /// Returns the time spent on the move, if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!(GgfMove::pass(Stone::White).time(), None);
+    /// ```
+    #[must_use]
+    pub fn time(&self) -> Option<f64> {
+        self.time
+    }
+
+    /// Parses the contents of a `B[...]` or `W[...]` tag, such as `F5/1.2/3`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::{Position, Stone};
+    ///
+    /// let mv = GgfMove::parse(Stone::Black, "f5/-2.5").unwrap();
+    /// assert_eq!(mv.position(), Some(Position::try_from("F5").unwrap()));
+    /// assert_eq!(mv.eval(), Some(-2.5));
+    ///
+    /// let mv = GgfMove::parse(Stone::White, "PA").unwrap();
+    /// assert_eq!(mv.position(), None);
+    /// ```
+    pub fn parse(stone: Stone, text: &str) -> Result<Self, GgfError> {
+        let invalid = || GgfError::InvalidMove(text.to_string());
+        let mut parts = text.trim().split('/');
+        let square = parts.next().ok_or_else(invalid)?.trim();
+        let parse_number = |part: Option<&str>| match part.map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(number) => number.parse::<f64>().map(Some).map_err(|_| invalid()),
+        };
+        let eval = parse_number(parts.next())?;
+        let time = parse_number(parts.next())?;
+
+        let position = match square.parse().map_err(|_| invalid())? {
+            Action::Pass => None,
+            Action::Move(pos) => Some(pos),
+        };
+        Ok(Self {
+            stone,
+            position,
+            eval,
+            time,
+        })
+    }
+
+    /// Formats the contents of the move tag, e.g. `F5/1.00`.
+    ///
+    /// This is the inverse of [`parse`].
+    ///
+    /// [`parse`]: crate::ggf::GgfMove::parse
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfMove;
+    /// use magpie::othello::Stone;
+    ///
+    /// let mv = GgfMove::pass(Stone::Black).with_eval(-3.0);
+    /// assert_eq!(mv.to_move_text(), "PA/-3.00");
+    /// ```
+    #[must_use]
+    pub fn to_move_text(&self) -> String {
+        let square = match self.position {
+            Some(pos) => format!("{pos:#}"),
+            None => "PA".to_string(),
+        };
+        match (self.eval, self.time) {
+            (None, None) => square,
+            (Some(eval), None) => format!("{square}/{eval:.2}"),
+            (None, Some(time)) => format!("{square}//{time:.2}"),
+            (Some(eval), Some(time)) => format!("{square}/{eval:.2}/{time:.2}"),
+        }
+    }
+}
+
+impl fmt::Display for GgfMove {
+    /// Formats the move as a GGF tag, e.g. `B[F5/1.00]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.stone, self.to_move_text())
+    }
+}
+
+impl fmt::Display for GgfGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(;")?;
+        for (key, value) in &self.tags {
+            write!(f, "{key}[{value}]")?;
+        }
+        write!(f, "BO[8 ")?;
+        for pos in Bitboard::FILLED.hot_bits() {
+            let c = match self.board.stone_at(pos) {
+                Some(Stone::Black) => '*',
+                Some(Stone::White) => 'O',
+                None => '-',
+            };
+            write!(f, "{c}")?;
+        }
+        let side = match self.first_player {
+            Stone::Black => '*',
+            Stone::White => 'O',
+        };
+        write!(f, " {side}]")?;
+        for mv in &self.moves {
+            write!(f, "{mv}")?;
+        }
+        write!(f, ";)")
+    }
+}
+
+impl FromStr for GgfGame {
+    type Err = GgfError;
+
+    /// Parses a single GGF game record.
+    ///
+    /// Unknown tags are preserved. The record must contain a `BO` tag.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let body = text
+            .strip_prefix("(;")
+            .and_then(|rest| rest.strip_suffix(";)"))
+            .ok_or(GgfError::InvalidSyntax)?;
+
+        let mut tags = Vec::new();
+        let mut board = None;
+        let mut moves = Vec::new();
+
+        let mut rest = body.trim_start();
+        while !rest.is_empty() {
+            let open = rest.find('[').ok_or(GgfError::InvalidSyntax)?;
+            let close = rest[open..].find(']').ok_or(GgfError::InvalidSyntax)? + open;
+            let key = rest[..open].trim();
+            let value = &rest[open + 1..close];
+            if key.is_empty() || !key.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(GgfError::InvalidSyntax);
+            }
+            match key {
+                "BO" => board = Some(parse_board(value)?),
+                "B" => moves.push(GgfMove::parse(Stone::Black, value)?),
+                "W" => moves.push(GgfMove::parse(Stone::White, value)?),
+                _ => tags.push((key.to_string(), value.to_string())),
+            }
+            rest = rest[close + 1..].trim_start();
+        }
+
+        let (board, first_player) = board.ok_or(GgfError::InvalidBoard)?;
+        Ok(Self {
+            tags,
+            board,
+            first_player,
+            moves,
+        })
+    }
+}
+
+/// Parses the contents of a `BO` tag, e.g. `8 ---...--- *`.
+///
+/// Black stones are denoted by `*` and white stones by `O`, while both `-`
+/// and `.` denote empty squares. The squares are listed from A1 to H8.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggf;
+/// use magpie::othello::{Board, Stone};
+///
+/// let text = "8 ---------------------------O*------*O--------------------------- *";
+/// let (board, next_player) = ggf::parse_board(text).unwrap();
+/// assert_eq!(board, Board::standard());
+/// assert_eq!(next_player, Stone::Black);
+/// ```
+pub fn parse_board(text: &str) -> Result<(Board, Stone), GgfError> {
+    let mut parts = text.split_whitespace();
+    if parts.next() != Some("8") {
+        return Err(GgfError::InvalidBoard);
+    }
+    // The squares may be split into ranks, so whitespace is ignored
+    let squares: Vec<char> = parts.flat_map(str::chars).collect();
+    let [squares @ .., side] = squares.as_slice() else {
+        return Err(GgfError::InvalidBoard);
+    };
+    if squares.len() != 64 {
+        return Err(GgfError::InvalidBoard);
+    }
+
+    let mut board = Board::empty();
+    for (pos, c) in Bitboard::FILLED.hot_bits().zip(squares) {
+        match c {
+            '*' => board.place_stone_unchecked(Stone::Black, pos.into()),
+            'O' => board.place_stone_unchecked(Stone::White, pos.into()),
+            '-' | '.' => {}
+            _ => return Err(GgfError::InvalidBoard),
+        }
+    }
+    let next_player = match side {
+        '*' => Stone::Black,
+        'O' => Stone::White,
+        _ => return Err(GgfError::InvalidBoard),
+    };
+    Ok((board, next_player))
+}
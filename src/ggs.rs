@@ -0,0 +1,469 @@
+use crate::{
+    ggf::{GgfError, GgfGame, GgfMove},
+    othello::{Game, Position, Stone},
+};
+use std::{
+    collections::HashMap,
+    error, fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    str::FromStr,
+    time::Duration,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// This enum represents errors that may occur when parsing server messages.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum GgsError {
+    /// Indicates that a recognized message was malformed.
+    InvalidMessage(String),
+    /// Indicates that a clock could not be parsed.
+    InvalidClock(String),
+    /// Indicates that the game embedded in a message was invalid.
+    InvalidGame(GgfError),
+}
+
+impl From<GgfError> for GgsError {
+    fn from(e: GgfError) -> Self {
+        GgsError::InvalidGame(e)
+    }
+}
+
+impl fmt::Display for GgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMessage(line) => write!(f, "invalid message \"{line}\""),
+            Self::InvalidClock(text) => write!(f, "invalid clock \"{text}\""),
+            Self::InvalidGame(_) => write!(f, "invalid game"),
+        }
+    }
+}
+
+impl error::Error for GgsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidGame(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A player as announced by the server.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Player {
+    /// The login name of the player.
+    pub name: String,
+    /// The rating of the player.
+    pub rating: f64,
+}
+
+/// A time control, as written by the server, e.g. `05:00/00:02/01:00`.
+///
+/// The three parts are the main time, the increment added after every move
+/// and the extension granted once the main time runs out. Missing parts
+/// default to zero, so `05:00//01:00` has no increment.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggs::Clock;
+/// use std::time::Duration;
+///
+/// let clock: Clock = "05:00//01:00".parse().unwrap();
+/// assert_eq!(clock.remaining, Duration::from_secs(300));
+/// assert_eq!(clock.increment, Duration::ZERO);
+/// assert_eq!(clock.extension, Duration::from_secs(60));
+/// assert_eq!(clock.to_string(), "05:00//01:00");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Clock {
+    /// The main thinking time left.
+    pub remaining: Duration,
+    /// The time added after every move.
+    pub increment: Duration,
+    /// The extra time granted once the main time runs out.
+    pub extension: Duration,
+}
+
+/// A request from another player to start a match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchRequest {
+    /// The identifier used to accept or decline the request, e.g. `.12`.
+    pub id: String,
+    /// The player asking for the match.
+    pub from: Player,
+    /// The player being asked.
+    pub to: Player,
+    /// The game type, e.g. `8` for standard Othello.
+    pub variant: String,
+    /// The proposed time control.
+    pub clock: Clock,
+}
+
+/// A match between two players.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchInfo {
+    /// The identifier of the match, e.g. `.27`.
+    pub id: String,
+    /// The player with the black stones.
+    pub black: Player,
+    /// The player with the white stones.
+    pub white: Player,
+    /// The game type, e.g. `8` for standard Othello.
+    pub variant: String,
+}
+
+/// The state of a game in progress, as sent by the server.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameUpdate {
+    /// The identifier of the match.
+    pub id: String,
+    /// The complete game record.
+    pub record: GgfGame,
+    /// The game obtained by replaying the record.
+    pub game: Game,
+    /// The clocks of black and white, if the server sent them.
+    pub clocks: Option<(Clock, Clock)>,
+}
+
+/// A message sent by the server.
+///
+/// Messages from the Othello service are prefixed with `/os:`. The
+/// following subset is understood, where `<ggf>` is a complete GGF record:
+///
+/// ```text
+/// /os: + <request> <rating> <from> <rating> <to> <variant> <clock>
+/// /os: + match <id> <rating> <black> <rating> <white> <variant>
+/// /os: join <id> <ggf>
+/// /os: update <id> <black clock> <white clock> <ggf>
+/// /os: - match <id> <rating> <black> <rating> <white> <variant> <score>
+/// ```
+///
+/// Every other line is returned as [`Message::Other`].
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggs::Message;
+///
+/// let line = "/os: + match .27 1520.0 alice 1480.5 bob 8";
+/// let Message::MatchStart(info) = line.parse().unwrap() else {
+///     panic!("expected a match start");
+/// };
+/// assert_eq!(info.id, ".27");
+/// assert_eq!(info.white.name, "bob");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Message {
+    /// Another player requested a match.
+    MatchRequest(MatchRequest),
+    /// A match has started.
+    MatchStart(MatchInfo),
+    /// The client joined a game and received its current state.
+    Join(GameUpdate),
+    /// A move was made in a game the client is part of.
+    Update(GameUpdate),
+    /// A match has ended with the specified score, from black's perspective.
+    MatchEnd(MatchInfo, f64),
+    /// Any other message.
+    Other(String),
+}
+
+/// A command that can be sent to the server.
+///
+/// Commands are formatted with [`Display`] into the line the server expects.
+///
+/// [`Display`]: std::fmt::Display
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggs::Command;
+/// use magpie::othello::Position;
+///
+/// let command = Command::Play {
+///     id: ".27".to_string(),
+///     position: Some(Position::try_from("f5").unwrap()),
+///     eval: Some(1.5),
+/// };
+/// assert_eq!(command.to_string(), "t /os play .27 F5/1.50");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// Logs in with the specified name and password.
+    Login {
+        /// The login name.
+        name: String,
+        /// The password.
+        password: String,
+    },
+    /// Accepts the match request with the specified identifier.
+    Accept(String),
+    /// Declines the match request with the specified identifier.
+    Decline(String),
+    /// Plays a move, or passes if no position is given.
+    Play {
+        /// The identifier of the match.
+        id: String,
+        /// The position to play, or `None` to pass.
+        position: Option<Position>,
+        /// An optional evaluation of the move, in discs.
+        eval: Option<f64>,
+    },
+    /// Sends a raw line to the server.
+    Raw(String),
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Login { name, password } => write!(f, "{name}\n{password}"),
+            Self::Accept(id) => write!(f, "t /os accept {id}"),
+            Self::Decline(id) => write!(f, "t /os decline {id}"),
+            Self::Play { id, position, eval } => {
+                // The stone is irrelevant when formatting the move itself
+                let mv = match position {
+                    Some(pos) => GgfMove::new(Stone::Black, *pos),
+                    None => GgfMove::pass(Stone::Black),
+                };
+                let mv = match eval {
+                    Some(eval) => mv.with_eval(*eval),
+                    None => mv,
+                };
+                write!(f, "t /os play {id} {}", mv.to_move_text())
+            }
+            Self::Raw(line) => write!(f, "{line}"),
+        }
+    }
+}
+
+impl FromStr for Clock {
+    type Err = GgsError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || GgsError::InvalidClock(text.to_string());
+        let mut parts = text.split('/');
+        let mut next = || parts.next().map_or(Ok(Duration::ZERO), parse_duration);
+        let clock = Clock {
+            remaining: next().map_err(|()| invalid())?,
+            increment: next().map_err(|()| invalid())?,
+            extension: next().map_err(|()| invalid())?,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(clock)
+    }
+}
+
+impl fmt::Display for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = |duration: Duration| {
+            let seconds = duration.as_secs();
+            format!("{:02}:{:02}", seconds / 60, seconds % 60)
+        };
+        // Zero increments and extensions are left out, as the server does
+        let optional = |duration: Duration| {
+            if duration.is_zero() {
+                String::new()
+            } else {
+                format(duration)
+            }
+        };
+        write!(f, "{}", format(self.remaining))?;
+        if !self.increment.is_zero() || !self.extension.is_zero() {
+            write!(
+                f,
+                "/{}/{}",
+                optional(self.increment),
+                optional(self.extension)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Parses durations such as "", "30", "05:00" and "1:05:00"
+fn parse_duration(text: &str) -> Result<Duration, ()> {
+    if text.is_empty() {
+        return Ok(Duration::ZERO);
+    }
+    text.split(':')
+        .try_fold(0, |total: u64, part| {
+            part.parse::<u64>()
+                .ok()
+                .and_then(|n| total.checked_mul(60)?.checked_add(n))
+        })
+        .map(Duration::from_secs)
+        .ok_or(())
+}
+
+impl FromStr for Message {
+    type Err = GgsError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let Some(body) = line.strip_prefix("/os:") else {
+            return Ok(Message::Other(line.to_string()));
+        };
+        let invalid = || GgsError::InvalidMessage(line.to_string());
+        let words: Vec<&str> = body.split_whitespace().collect();
+
+        let message = match words.as_slice() {
+            ["+", "match", id, r1, black, r2, white, variant, ..] => Message::MatchStart(
+                match_info(id, r1, black, r2, white, variant).ok_or_else(invalid)?,
+            ),
+            ["-", "match", id, r1, black, r2, white, variant, score, ..] => {
+                let info = match_info(id, r1, black, r2, white, variant).ok_or_else(invalid)?;
+                let score = score.parse().map_err(|_| invalid())?;
+                Message::MatchEnd(info, score)
+            }
+            ["+", id, r1, from, r2, to, variant, clock, ..] if id.starts_with('.') => {
+                Message::MatchRequest(MatchRequest {
+                    id: (*id).to_string(),
+                    from: player(r1, from).ok_or_else(invalid)?,
+                    to: player(r2, to).ok_or_else(invalid)?,
+                    variant: (*variant).to_string(),
+                    clock: clock.parse()?,
+                })
+            }
+            ["join", id, ..] => Message::Join(game_update(id, None, body)?),
+            ["update", id, black, white, ..] => {
+                let clocks = (black.parse()?, white.parse()?);
+                Message::Update(game_update(id, Some(clocks), body)?)
+            }
+            _ => Message::Other(line.to_string()),
+        };
+        Ok(message)
+    }
+}
+
+fn player(rating: &str, name: &str) -> Option<Player> {
+    Some(Player {
+        name: name.to_string(),
+        rating: rating.parse().ok()?,
+    })
+}
+
+fn match_info(
+    id: &str,
+    black_rating: &str,
+    black: &str,
+    white_rating: &str,
+    white: &str,
+    variant: &str,
+) -> Option<MatchInfo> {
+    Some(MatchInfo {
+        id: id.to_string(),
+        black: player(black_rating, black)?,
+        white: player(white_rating, white)?,
+        variant: variant.to_string(),
+    })
+}
+
+fn game_update(
+    id: &str,
+    clocks: Option<(Clock, Clock)>,
+    body: &str,
+) -> Result<GameUpdate, GgsError> {
+    let start = body.find("(;").ok_or(GgfError::InvalidSyntax)?;
+    let end = body.rfind(";)").ok_or(GgfError::InvalidSyntax)? + 2;
+    let record: GgfGame = body
+        .get(start..end)
+        .ok_or(GgfError::InvalidSyntax)?
+        .parse()?;
+    let game = record.to_game()?;
+    Ok(GameUpdate {
+        id: id.to_string(),
+        record,
+        game,
+        clocks,
+    })
+}
+
+/// A client connected to a GGS-style Othello server.
+///
+/// The client reads and parses messages from the server and keeps track of
+/// the state of every game it has been told about, so that an engine only
+/// has to look up the current [`Game`] and [`send`] its move.
+///
+/// [`Game`]: crate::othello::Game
+/// [`send`]: crate::ggs::Client::send
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggs::{Client, Command, Message};
+/// use std::io::Cursor;
+///
+/// let input = "/os: + match .1 1500 alice 1500 bob 8\n";
+/// let mut client = Client::new(Cursor::new(input.as_bytes().to_vec()));
+/// let message = client.next_message().unwrap();
+/// assert!(matches!(message, Some(Message::MatchStart(_))));
+/// assert!(client.next_message().unwrap().is_none());
+/// ```
+#[derive(Debug)]
+pub struct Client<S> {
+    stream: BufReader<S>,
+    games: HashMap<String, Game>,
+}
+
+impl Client<TcpStream> {
+    /// Connects to a server over TCP.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        Ok(Self::new(TcpStream::connect(addr)?))
+    }
+}
+
+impl<S: Read + Write> Client<S> {
+    /// Returns a client communicating over the specified stream.
+    #[must_use]
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+            games: HashMap::new(),
+        }
+    }
+
+    /// Sends a command to the server.
+    pub fn send(&mut self, command: &Command) -> io::Result<()> {
+        let stream = self.stream.get_mut();
+        writeln!(stream, "{command}")?;
+        stream.flush()
+    }
+
+    /// Reads and parses the next message from the server.
+    ///
+    /// Returns `None` once the server closes the connection. Malformed
+    /// messages are reported as [`io::ErrorKind::InvalidData`] errors.
+    pub fn next_message(&mut self) -> io::Result<Option<Message>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.stream.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+        let message: Message = line
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match &message {
+            Message::Join(update) | Message::Update(update) => {
+                self.games.insert(update.id.clone(), update.game.clone());
+            }
+            Message::MatchEnd(info, _) => {
+                self.games.remove(&info.id);
+            }
+            _ => {}
+        }
+        Ok(Some(message))
+    }
+
+    /// Returns the latest known state of the specified game.
+    #[must_use]
+    pub fn game(&self, id: &str) -> Option<&Game> {
+        self.games.get(id)
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::missing_panics_doc)]
 #![allow(clippy::missing_errors_doc)]
 #![doc(html_logo_url = "https://cdn.github.emileng.se/repo/magpie/logo.svg")]
@@ -14,6 +15,27 @@
 //! Supporting types include [`Bitboard`] and [`Position`] for board representation,
 //! [`Stone`] for player identity, and [`BoardDisplay`] for rendering boards.
 //!
+//! On top of these, the [`search`] module provides a simple alpha-beta engine,
+//! the [`ggf`] module reads and writes game records, and the `nboard` module
+//! (behind the `nboard` feature) connects engines to the NBoard GUI. Players
+//! implementing the [`agent`] trait can be pitted against each other with the
+//! [`tournament`] module, while the [`variant`] module plays Othello on
+//! 6x6 and 10x10 boards. Boards can be rendered as SVG images with the
+//! `svg` module, behind the `svg` feature. With the `serde` feature, the
+//! `serde` module offers human-readable alternatives to the default serde
+//! representations. The `quickcheck` and `proptest` features enable the
+//! `arbitrary` module, which generates boards and games reachable from the
+//! standard opening for property tests.
+//!
+//! ## `no_std`
+//!
+//! Everything besides the core types requires the standard library, through
+//! the `std` feature which is enabled by default. Without it, magpie builds
+//! under `#![no_std]` without `alloc`: [`Bitboard`], [`Position`], [`Board`],
+//! [`Game`] and [`Stone`] remain available, while text rendering, diagram
+//! parsing and the remaining modules are left out. Notation is available
+//! through [`Position::notation`], which returns a `&'static str`.
+//!
 //! ## Getting Started
 //!
 //! ```rust
@@ -45,8 +67,46 @@
 //! [`Bitboard`]: crate::othello::Bitboard
 //! [`Game`]: crate::othello::Game
 //! [`Position`]: crate::othello::Position
+//! [`Position::notation`]: crate::othello::Position::notation
 //! [`Stone`]: crate::othello::Stone
 //! [`othello`]: crate::othello
+//! [`ggf`]: crate::ggf
+//! [`search`]: crate::search
+//! [`agent`]: crate::agent::Agent
+//! [`tournament`]: crate::tournament
+//! [`variant`]: crate::variant
 
+/// Players that pick actions for one side of a game.
+#[cfg(feature = "std")]
+pub mod agent;
+/// Random generation of positions, boards and games for property tests.
+#[cfg(any(feature = "quickcheck", feature = "proptest"))]
+pub mod arbitrary;
+/// Parsing and formatting of game records in the Generic Game Format (GGF).
+#[cfg(feature = "std")]
+pub mod ggf;
+/// Client for GGS-style Othello servers.
+#[cfg(feature = "ggs")]
+pub mod ggs;
+/// Drives engines through the NBoard text protocol.
+#[cfg(feature = "nboard")]
+pub mod nboard;
 /// Contains core structures and functions for playing Othello
 pub mod othello;
+/// Pseudo-random number generation for agents and setups.
+mod rng;
+/// Alpha-beta search and endgame solving.
+#[cfg(feature = "std")]
+pub mod search;
+/// Alternative serde representations, behind the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod serde;
+/// Rendering of boards and games as SVG images.
+#[cfg(feature = "svg")]
+pub mod svg;
+/// Matches between agents and the statistics to compare them.
+#[cfg(feature = "std")]
+pub mod tournament;
+/// Othello on 6x6, 10x10 and other board sizes.
+#[cfg(feature = "std")]
+pub mod variant;
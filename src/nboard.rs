@@ -0,0 +1,314 @@
+use crate::{
+    ggf::{GgfError, GgfGame, GgfMove},
+    othello::{Game, Position},
+    search::Search,
+};
+use std::io::{self, BufRead, Write};
+
+/// An engine that can be driven through the NBoard protocol.
+///
+/// Implement this trait to plug a custom engine into a [`Session`]. The
+/// session takes care of parsing commands, tracking the game and formatting
+/// responses, while the engine only has to pick and evaluate moves.
+///
+/// Evaluations are expressed in discs from the perspective of the player to
+/// move.
+///
+/// [`Session`]: crate::nboard::Session
+pub trait Engine {
+    /// Returns the name the engine reports to NBoard.
+    fn name(&self) -> &str;
+
+    /// Sets the maximum search depth requested by the user.
+    fn set_depth(&mut self, depth: u8);
+
+    /// Picks a move for the player to move in the specified game.
+    ///
+    /// Returns `None` if the player has to pass.
+    fn go(&mut self, game: &Game) -> Option<Hint>;
+
+    /// Evaluates up to `count` of the best moves in the specified game, sorted
+    /// from best to worst.
+    fn hints(&mut self, game: &Game, count: usize) -> Vec<Hint>;
+
+    /// Called when the user asks the engine to learn from a finished game.
+    ///
+    /// The default implementation does nothing.
+    fn learn(&mut self, _game: &Game) {}
+}
+
+/// An evaluated move reported by an [`Engine`].
+///
+/// [`Engine`]: crate::nboard::Engine
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hint {
+    /// The move that was evaluated.
+    pub position: Position,
+    /// The evaluation of the move, in discs.
+    pub eval: f64,
+    /// The depth the move was searched to.
+    pub depth: u8,
+    /// Whether the evaluation is the exact outcome of the game.
+    pub exact: bool,
+}
+
+/// The default engine, backed by an alpha-beta [`Search`].
+///
+/// [`Search`]: crate::search::Search
+///
+/// # Examples
+/// ```rust
+/// use magpie::nboard::{Engine, SearchEngine};
+/// use magpie::othello::Game;
+///
+/// let mut engine = SearchEngine::default();
+/// engine.set_depth(2);
+/// let hint = engine.go(&Game::new()).unwrap();
+/// assert!(Game::new().is_legal_move(hint.position));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SearchEngine {
+    search: Search,
+}
+
+impl SearchEngine {
+    /// Returns an engine that uses the specified search.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::nboard::SearchEngine;
+    /// use magpie::search::Search;
+    ///
+    /// let engine = SearchEngine::new(Search::new(4));
+    /// ```
+    #[must_use]
+    pub fn new(search: Search) -> Self {
+        Self { search }
+    }
+}
+
+impl Engine for SearchEngine {
+    fn name(&self) -> &str {
+        "magpie"
+    }
+
+    fn set_depth(&mut self, depth: u8) {
+        self.search = self.search.with_depth(depth);
+    }
+
+    fn go(&mut self, game: &Game) -> Option<Hint> {
+        self.search
+            .best_move(&game.board(), game.current_turn())
+            .map(|evaluation| self.to_hint(evaluation))
+    }
+
+    fn hints(&mut self, game: &Game, count: usize) -> Vec<Hint> {
+        self.search
+            .evaluate_moves(&game.board(), game.current_turn())
+            .into_iter()
+            .take(count)
+            .map(|evaluation| self.to_hint(evaluation))
+            .collect()
+    }
+}
+
+impl SearchEngine {
+    fn to_hint(&self, evaluation: crate::search::Evaluation) -> Hint {
+        Hint {
+            position: evaluation.position,
+            eval: f64::from(evaluation.score) / 100.0,
+            depth: self.search.depth(),
+            exact: evaluation.exact,
+        }
+    }
+}
+
+/// Indicates whether a [`Session`] should keep reading commands.
+///
+/// [`Session`]: crate::nboard::Session
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Control {
+    /// The session expects more commands.
+    Continue,
+    /// The GUI asked the engine to quit.
+    Quit,
+}
+
+/// A connection between an [`Engine`] and an NBoard-compatible GUI.
+///
+/// The session implements the text protocol spoken by
+/// [NBoard](https://github.com/weltyc/nboard): it reads one command per line,
+/// keeps track of the current game and writes the engine's responses.
+///
+/// The following commands are supported: `nboard`, `ping`, `set depth`,
+/// `set game`, `set contempt`, `move`, `hint`, `go`, `learn` and `quit`.
+/// Unknown commands are ignored, as the protocol requires.
+///
+/// [`Engine`]: crate::nboard::Engine
+///
+/// # Examples
+/// ```rust
+/// use magpie::nboard::{SearchEngine, Session};
+///
+/// let input = "nboard 2\nset depth 1\nping 1\n";
+/// let mut output = Vec::new();
+///
+/// let mut session = Session::new(SearchEngine::default());
+/// session.run(input.as_bytes(), &mut output).unwrap();
+///
+/// let output = String::from_utf8(output).unwrap();
+/// assert_eq!(output, "set myname magpie\npong 1\n");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Session<E> {
+    engine: E,
+    game: Game,
+}
+
+impl<E: Engine> Session<E> {
+    /// Returns a session driving the specified engine, starting from the
+    /// standard opening position.
+    #[must_use]
+    pub fn new(engine: E) -> Self {
+        Self {
+            engine,
+            game: Game::new(),
+        }
+    }
+
+    /// Returns the game as currently known to the session.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::nboard::{SearchEngine, Session};
+    /// use magpie::othello::Game;
+    ///
+    /// let session = Session::new(SearchEngine::default());
+    /// assert_eq!(session.game(), &Game::new());
+    /// ```
+    #[must_use]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns the engine driven by the session.
+    #[must_use]
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    /// Reads commands from `input` until it is exhausted or the GUI sends
+    /// `quit`, writing responses to `output`.
+    ///
+    /// The output is flushed after every command.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            let control = self.handle(&line?, &mut output)?;
+            output.flush()?;
+            if control == Control::Quit {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a single command, writing any response to `output`.
+    ///
+    /// Malformed commands are reported to the GUI as a `status` message
+    /// rather than as an error, since the protocol has no other way of
+    /// signaling failure. Only I/O errors are returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::nboard::{Control, SearchEngine, Session};
+    /// use magpie::othello::Stone;
+    ///
+    /// let mut session = Session::new(SearchEngine::default());
+    /// let mut output = Vec::new();
+    /// let control = session.handle("move F5", &mut output).unwrap();
+    /// assert_eq!(control, Control::Continue);
+    /// assert_eq!(session.game().current_turn(), Stone::White);
+    /// ```
+    pub fn handle<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<Control> {
+        let line = line.trim();
+        let (command, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let args = args.trim();
+
+        match command {
+            "nboard" => writeln!(output, "set myname {}", self.engine.name())?,
+            "ping" => writeln!(output, "pong {args}")?,
+            "set" => self.set(args, output)?,
+            "move" => {
+                if let Err(e) = self.play(args) {
+                    writeln!(output, "status {e}")?;
+                }
+            }
+            "hint" => self.hint(args, output)?,
+            "go" => self.go(output)?,
+            "learn" => {
+                self.engine.learn(&self.game);
+                writeln!(output, "learned")?;
+            }
+            "quit" => return Ok(Control::Quit),
+            _ => {}
+        }
+        Ok(Control::Continue)
+    }
+
+    fn set<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        let (option, value) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        let value = value.trim();
+        match option {
+            "depth" => match value.parse() {
+                Ok(depth) => self.engine.set_depth(depth),
+                Err(_) => writeln!(output, "status invalid depth \"{value}\"")?,
+            },
+            "game" => match value.parse::<GgfGame>().and_then(|record| record.to_game()) {
+                Ok(game) => self.game = game,
+                Err(e) => writeln!(output, "status {e}")?,
+            },
+            // Contempt and any unknown options are accepted but ignored
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn play(&mut self, args: &str) -> Result<(), GgfError> {
+        let mv = GgfMove::parse(self.game.current_turn(), args)?;
+        match mv.position() {
+            Some(pos) => self.game.play(pos)?,
+            None => self.game.pass()?,
+        }
+        Ok(())
+    }
+
+    fn hint<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        let count = args.parse().unwrap_or(1);
+        writeln!(output, "status Thinking")?;
+        for hint in self.engine.hints(&self.game, count) {
+            let depth = if hint.exact {
+                "100%".to_string()
+            } else {
+                hint.depth.to_string()
+            };
+            writeln!(
+                output,
+                "search {:#} {:.2} 0 {depth}",
+                hint.position, hint.eval
+            )?;
+        }
+        writeln!(output, "status")
+    }
+
+    fn go<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "status Thinking")?;
+        let mv = match self.engine.go(&self.game) {
+            Some(hint) => {
+                GgfMove::new(self.game.current_turn(), hint.position).with_eval(hint.eval)
+            }
+            None => GgfMove::pass(self.game.current_turn()),
+        };
+        writeln!(output, "=== {}", mv.to_move_text())?;
+        writeln!(output, "status")
+    }
+}
@@ -0,0 +1,200 @@
+use crate::othello::{Position, PositionError};
+use core::{fmt, iter::FusedIterator, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents the two kinds of actions a player can take on their turn.
+///
+/// Actions can be applied to a game with [`Game::apply`]. They also serve as
+/// move tokens when parsing text, where a pass is written as `pass`, `PA`
+/// or `--`. Concatenated moves, as found in transcripts, can be split into
+/// actions with [`Action::tokens`].
+///
+/// Games on boards of other sizes use their own squares, which is what the
+/// type parameter is for. It defaults to the [`Position`] of the standard
+/// board.
+///
+/// [`Game::apply`]: crate::othello::Game::apply
+/// [`Position`]: crate::othello::Position
+/// [`Action::tokens`]: crate::othello::Action::tokens
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Action, Game};
+///
+/// let mut game = Game::new();
+/// let pos = game.moves().hot_bits().next().unwrap();
+/// assert!(game.apply(Action::Move(pos)).is_ok());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Action<P = Position> {
+    /// Places a stone at the specified position.
+    Move(P),
+    /// Passes the turn to the opponent.
+    Pass,
+}
+
+impl Action {
+    /// Returns an iterator over the actions of concatenated moves, such as
+    /// `f5d6c3PAd3`.
+    ///
+    /// Every move is two characters long, and passes are written as `pass`,
+    /// `PA` or `--`, ignoring case. Whitespace between moves is skipped. The
+    /// text is parsed lazily and without allocating.
+    ///
+    /// The iterator stops after the first invalid token, which is then
+    /// available through [`ActionTokens::remainder`].
+    ///
+    /// [`ActionTokens::remainder`]: crate::othello::ActionTokens::remainder
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Action, Position};
+    ///
+    /// let actions: Vec<Action> = Action::tokens("f5 d6c3--")
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(actions.len(), 4);
+    /// assert_eq!(actions[0], Action::Move("f5".parse().unwrap()));
+    /// assert_eq!(actions[3], Action::Pass);
+    ///
+    /// let mut tokens = Action::tokens("f5z9d6");
+    /// assert!(tokens.next().unwrap().is_ok());
+    /// assert!(tokens.next().unwrap().is_err());
+    /// assert_eq!(tokens.remainder(), "z9d6");
+    /// assert_eq!(tokens.next(), None);
+    /// ```
+    #[must_use]
+    pub fn tokens(text: &str) -> ActionTokens<'_> {
+        ActionTokens {
+            remainder: text,
+            failed: false,
+        }
+    }
+}
+
+impl From<Position> for Action {
+    fn from(position: Position) -> Self {
+        Action::Move(position)
+    }
+}
+
+impl FromStr for Action {
+    type Err = PositionError;
+
+    /// Parses a single move token, which is either a position such as `e6`
+    /// or a pass written as `pass`, `PA` or `--`.
+    ///
+    /// The conversion is case-insensitive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Action;
+    ///
+    /// assert_eq!("PA".parse(), Ok(Action::Pass));
+    /// assert_eq!("--".parse(), Ok(Action::Pass));
+    /// assert!(matches!("E6".parse(), Ok(Action::Move(_))));
+    /// assert!("e9".parse::<Action>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if is_pass(text.as_bytes()) {
+            return Ok(Action::Pass);
+        }
+        Position::from_notation(text)
+            .map(Action::Move)
+            .ok_or(PositionError::InvalidNotation)
+    }
+}
+
+impl fmt::Display for Action {
+    /// Formats moves like [`Position`] and passes as `pass`, or in uppercase
+    /// as `E6` and `PA` with the alternate flag (`{:#}`).
+    ///
+    /// [`Position`]: crate::othello::Position
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Action;
+    ///
+    /// let mv: Action = "e6".parse().unwrap();
+    /// assert_eq!(format!("{mv} {}", Action::Pass), "e6 pass");
+    /// assert_eq!(format!("{mv:#} {:#}", Action::Pass), "E6 PA");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Move(pos) => fmt::Display::fmt(pos, f),
+            Self::Pass if f.alternate() => f.pad("PA"),
+            Self::Pass => f.pad("pass"),
+        }
+    }
+}
+
+/// A streaming tokenizer that splits concatenated moves into actions.
+///
+/// Created by [`Action::tokens`].
+///
+/// [`Action::tokens`]: crate::othello::Action::tokens
+#[derive(Clone, Debug)]
+pub struct ActionTokens<'a> {
+    remainder: &'a str,
+    failed: bool,
+}
+
+impl<'a> ActionTokens<'a> {
+    /// Returns the text that has not been parsed yet.
+    ///
+    /// After an invalid token has been encountered, the remainder starts
+    /// with that token.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Action;
+    ///
+    /// let mut tokens = Action::tokens("f5 d6");
+    /// tokens.next();
+    /// assert_eq!(tokens.remainder(), " d6");
+    /// ```
+    #[must_use]
+    pub fn remainder(&self) -> &'a str {
+        self.remainder
+    }
+
+    fn fail(&mut self) -> Option<Result<Action, PositionError>> {
+        self.failed = true;
+        Some(Err(PositionError::InvalidNotation))
+    }
+}
+
+impl Iterator for ActionTokens<'_> {
+    type Item = Result<Action, PositionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        self.remainder = self.remainder.trim_start();
+        let bytes = self.remainder.as_bytes();
+        // The lengths are only taken from matched ASCII bytes, so the
+        // remainder is always split at a character boundary
+        let (action, len) = match bytes {
+            [] => return None,
+            [a, b, c, d, ..] if is_pass(&[*a, *b, *c, *d]) => (Action::Pass, 4),
+            [a, b, ..] if is_pass(&[*a, *b]) => (Action::Pass, 2),
+            [file, rank, ..] => match Position::from_ascii(*file, *rank) {
+                Some(pos) => (Action::Move(pos), 2),
+                None => return self.fail(),
+            },
+            [_] => return self.fail(),
+        };
+        self.remainder = &self.remainder[len..];
+        Some(Ok(action))
+    }
+}
+
+impl FusedIterator for ActionTokens<'_> {}
+
+fn is_pass(token: &[u8]) -> bool {
+    token.eq_ignore_ascii_case(b"pass") || token.eq_ignore_ascii_case(b"pa") || token == b"--"
+}
@@ -1,4 +1,12 @@
-use crate::othello::{Position, constants::POSITIONS};
+use crate::othello::{
+    Direction, Position,
+    constants::{SHIFT_DIRS, SHIFT_MASKS, SHIFT_RAYS},
+};
+use core::{
+    error, fmt,
+    iter::{FusedIterator, Rev},
+    str::FromStr,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -36,6 +44,77 @@ impl Bitboard {
     /// ```
     pub const EMPTY: Self = Bitboard(0);
 
+    /// The four corners A1, H1, A8 and H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Square};
+    ///
+    /// assert_eq!(Bitboard::CORNERS.count_set(), 4);
+    /// assert!(Bitboard::CORNERS & Square::H8.position() != 0);
+    /// ```
+    pub const CORNERS: Self = Bitboard(0x8100_0000_0000_0081);
+
+    /// The X-squares B2, G2, B7 and G7, diagonally adjacent to the corners.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Square};
+    ///
+    /// assert_eq!(Bitboard::X_SQUARES.count_set(), 4);
+    /// assert!(Bitboard::X_SQUARES & Square::G7.position() != 0);
+    /// ```
+    pub const X_SQUARES: Self = Bitboard(0x0042_0000_0000_4200);
+
+    /// The C-squares on the edges next to the corners, such as B1 and A2.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Square};
+    ///
+    /// assert_eq!(Bitboard::C_SQUARES.count_set(), 8);
+    /// assert!(Bitboard::C_SQUARES & Square::B1.position() != 0);
+    /// ```
+    pub const C_SQUARES: Self = Bitboard(0x4281_0000_0000_8142);
+
+    /// The 28 squares on the edges of the board, including the corners.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// assert_eq!(Bitboard::EDGES.count_set(), 28);
+    /// assert!((Bitboard::EDGES & Bitboard::CORNERS) == Bitboard::CORNERS);
+    /// ```
+    pub const EDGES: Self = Bitboard(0xff81_8181_8181_81ff);
+
+    /// The four center squares D4, E4, D5 and E5 of the starting position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Board};
+    ///
+    /// assert_eq!(!Board::standard().empty_squares(), Bitboard::CENTER);
+    /// ```
+    pub const CENTER: Self = Bitboard(0x0000_0018_1800_0000);
+
+    /// Creates a bitboard from a u64.
+    ///
+    /// Unlike the [`From`] implementation, this constructor can be used in
+    /// `const` contexts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// const RANK_1: Bitboard = Bitboard::from_raw(0xff00_0000_0000_0000);
+    /// assert_eq!(RANK_1.count_set(), 8);
+    /// ```
+    #[must_use]
+    pub const fn from_raw(bitboard: u64) -> Self {
+        Bitboard(bitboard)
+    }
+
     /// Retrieves the underlying u64.
     ///
     /// # Examples
@@ -45,7 +124,7 @@ impl Bitboard {
     /// assert_eq!(Bitboard::EMPTY.raw(), 0);
     /// ```
     #[must_use]
-    pub fn raw(self) -> u64 {
+    pub const fn raw(self) -> u64 {
         self.0
     }
 
@@ -58,7 +137,7 @@ impl Bitboard {
     /// assert!(Bitboard::EMPTY.is_empty());
     /// ```
     #[must_use]
-    pub fn is_empty(self) -> bool {
+    pub const fn is_empty(self) -> bool {
         self.0 == 0
     }
 
@@ -72,7 +151,7 @@ impl Bitboard {
     /// ```
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
-    pub fn count_set(self) -> u8 {
+    pub const fn count_set(self) -> u8 {
         self.0.count_ones() as u8
     }
 
@@ -86,7 +165,7 @@ impl Bitboard {
     /// ```
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
-    pub fn count_empty(self) -> u8 {
+    pub const fn count_empty(self) -> u8 {
         self.0.count_zeros() as u8
     }
 
@@ -106,17 +185,23 @@ impl Bitboard {
     /// 000 => 000 => 000 => 000 => 000 => 000 => 000 => 000 => 000
     /// 000    000    000    000    000    000    100    010    001
     /// ```
-    /// The iterator always return 64 bitboards.
+    /// The iterator always return 64 bitboards, from A1 to H8. It can also
+    /// be traversed from H8 to A1, since it is double-ended.
     ///
     /// # Examples
     /// ```rust
     /// use magpie::othello::Bitboard;
     ///
     /// assert_eq!(Bitboard::FILLED.bits().len(), 64);
+    /// assert_eq!(Bitboard::FILLED.bits().next_back(), Some(Bitboard::from(1)));
     ///  ```
     #[must_use]
-    pub fn bits(self) -> impl ExactSizeIterator<Item = Bitboard> {
-        POSITIONS.iter().map(move |m| self & *m)
+    pub const fn bits(self) -> Bits {
+        Bits {
+            bitboard: self,
+            front: 0,
+            back: 64,
+        }
     }
 
     /// Extracts each bit set to one as its own bitboard.
@@ -136,6 +221,10 @@ impl Bitboard {
     /// 000    001
     /// ```
     ///
+    /// Positions are yielded from A1 to H8, which is the same order as
+    /// [`hot_bits_msb_first`]. The iterator is double-ended, so it may be
+    /// traversed from both ends at once.
+    ///
     /// # Examples
     /// ```rust
     /// use magpie::othello::Bitboard;
@@ -143,62 +232,512 @@ impl Bitboard {
     /// let b: Bitboard = u64::from(u32::MAX).into();
     /// assert_eq!(b.hot_bits().len(), 32);
     ///  ```
+    ///
+    /// [`hot_bits_msb_first`]: crate::othello::Bitboard::hot_bits_msb_first
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub const fn hot_bits(self) -> HotBits {
+        HotBits { bitboard: self }
+    }
+
+    /// Extracts each bit set to one as a position, starting with the most
+    /// significant bit.
+    ///
+    /// The most significant bit is A1, so positions are yielded from A1 to
+    /// H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let b = Bitboard::from(0x8000_0000_0000_0001);
+    /// let positions: Vec<Position> = b.hot_bits_msb_first().collect();
+    /// assert_eq!(positions[0].notation(), "a1");
+    /// assert_eq!(positions[1].notation(), "h8");
+    ///  ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub const fn hot_bits_msb_first(self) -> HotBits {
+        self.hot_bits()
+    }
+
+    /// Extracts each bit set to one as a position, starting with the least
+    /// significant bit.
+    ///
+    /// The least significant bit is H8, so positions are yielded from H8 to
+    /// A1. This is usually the fastest order to traverse a bitboard in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let b = Bitboard::from(0x8000_0000_0000_0001);
+    /// let positions: Vec<Position> = b.hot_bits_lsb_first().collect();
+    /// assert_eq!(positions[0].notation(), "h8");
+    /// assert_eq!(positions[1].notation(), "a1");
+    ///  ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn hot_bits_lsb_first(self) -> Rev<HotBits> {
+        self.hot_bits().rev()
+    }
+
+    /// Removes and returns the first set bit in the order of [`hot_bits`],
+    /// which is the most significant one, or `None` if the bitboard is
+    /// empty.
+    ///
+    /// [`hot_bits`]: crate::othello::Bitboard::hot_bits
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let mut b = Bitboard::from(0x8000_0000_0000_0001);
+    /// assert_eq!(b.pop_first(), Position::from_raw(1 << 63));
+    /// assert_eq!(b, Bitboard::from(1));
+    /// ```
+    pub const fn pop_first(&mut self) -> Option<Position> {
+        if self.0 == 0 {
+            return None;
+        }
+        let position = 1 << (63 - self.0.leading_zeros());
+        self.0 ^= position;
+        Some(Position::new_unchecked(position))
+    }
+
+    /// Removes and returns the last set bit in the order of [`hot_bits`],
+    /// which is the least significant one, or `None` if the bitboard is
+    /// empty.
+    ///
+    /// [`hot_bits`]: crate::othello::Bitboard::hot_bits
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let mut b = Bitboard::from(0x8000_0000_0000_0001);
+    /// assert_eq!(b.pop_last(), Position::from_raw(1));
+    /// assert_eq!(b, Bitboard::from(1 << 63));
+    /// ```
+    pub const fn pop_last(&mut self) -> Option<Position> {
+        if self.0 == 0 {
+            return None;
+        }
+        let position = self.0 & self.0.wrapping_neg();
+        self.0 &= self.0 - 1;
+        Some(Position::new_unchecked(position))
+    }
+
+    /// Moves every set bit one square in the specified direction.
+    ///
+    /// Unlike the raw `<<` and `>>` operators, bits never wrap around to the
+    /// other side of the board. Bits moved off the board are discarded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Direction, Position};
+    ///
+    /// let h1 = Bitboard::from(Position::try_from("h1").unwrap());
+    /// let g2 = Bitboard::from(Position::try_from("g2").unwrap());
+    /// assert_eq!(h1.shift(Direction::SouthWest), g2);
+    /// assert_eq!(h1.shift(Direction::East), Bitboard::EMPTY);
+    /// ```
     #[must_use]
-    pub fn hot_bits(self) -> impl ExactSizeIterator<Item = Position> {
-        let positions = HotBits {
-            remaining: self.count_set(),
-            bitboard: self,
-        };
-        positions.into_iter()
+    pub const fn shift(self, direction: Direction) -> Bitboard {
+        let index = direction.index();
+        let masked = self.0 & SHIFT_MASKS[index];
+        let shift = SHIFT_DIRS[index];
+        if shift > 0 {
+            Bitboard(masked >> shift)
+        } else {
+            Bitboard(masked << -shift)
+        }
+    }
+
+    /// Moves every set bit one square north, towards rank 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let rank_2 = Bitboard::from(0x00ff_0000_0000_0000);
+    /// assert_eq!(rank_2.north().raw(), 0xff00_0000_0000_0000);
+    /// assert_eq!(rank_2.north().north(), Bitboard::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn north(self) -> Bitboard {
+        self.shift(Direction::North)
+    }
+
+    /// Moves every set bit one square north east, towards rank 1 and file H.
+    #[must_use]
+    pub const fn north_east(self) -> Bitboard {
+        self.shift(Direction::NorthEast)
+    }
+
+    /// Moves every set bit one square east, towards file H.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let file_a = Bitboard::from(0x8080_8080_8080_8080);
+    /// assert_eq!(file_a.east().raw(), 0x4040_4040_4040_4040);
+    /// ```
+    #[must_use]
+    pub const fn east(self) -> Bitboard {
+        self.shift(Direction::East)
+    }
+
+    /// Moves every set bit one square south east, towards rank 8 and file H.
+    #[must_use]
+    pub const fn south_east(self) -> Bitboard {
+        self.shift(Direction::SouthEast)
+    }
+
+    /// Moves every set bit one square south, towards rank 8.
+    #[must_use]
+    pub const fn south(self) -> Bitboard {
+        self.shift(Direction::South)
+    }
+
+    /// Moves every set bit one square south west, towards rank 8 and file A.
+    #[must_use]
+    pub const fn south_west(self) -> Bitboard {
+        self.shift(Direction::SouthWest)
+    }
+
+    /// Moves every set bit one square west, towards file A.
+    #[must_use]
+    pub const fn west(self) -> Bitboard {
+        self.shift(Direction::West)
+    }
+
+    /// Moves every set bit one square north west, towards rank 1 and file A.
+    #[must_use]
+    pub const fn north_west(self) -> Bitboard {
+        self.shift(Direction::NorthWest)
+    }
+
+    /// Returns the squares adjacent to any set bit, in any of the eight
+    /// directions, that are not set themselves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Bitboard::from(Position::try_from("a1").unwrap());
+    /// assert_eq!(a1.neighbors().count_set(), 3);
+    ///
+    /// let d4 = Bitboard::from(Position::try_from("d4").unwrap());
+    /// assert_eq!(d4.neighbors().count_set(), 8);
+    /// ```
+    #[must_use]
+    pub const fn neighbors(self) -> Bitboard {
+        Bitboard(self.dilate().0 & !self.0)
+    }
+
+    /// Returns the set bits together with all squares adjacent to them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let d4 = Bitboard::from(Position::try_from("d4").unwrap());
+    /// assert_eq!(d4.dilate().count_set(), 9);
+    /// assert_eq!(d4.dilate(), d4 | d4.neighbors());
+    /// ```
+    #[must_use]
+    pub const fn dilate(self) -> Bitboard {
+        let mut dilated = self.0;
+        let mut i = 0;
+        while i < Direction::ALL.len() {
+            dilated |= self.shift(Direction::ALL[i]).0;
+            i += 1;
+        }
+        Bitboard(dilated)
+    }
+
+    /// Returns the squares of the mask that are connected to the set bits,
+    /// stepping through adjacent squares of the mask in any direction.
+    ///
+    /// Set bits outside of the mask are ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Bitboard::from(Position::try_from("a1").unwrap());
+    /// let file_a = Bitboard::from(0x8080_8080_8080_8080);
+    /// let file_h = Bitboard::from(0x0101_0101_0101_0101);
+    /// assert_eq!(a1.flood_fill(file_a | file_h), file_a);
+    /// ```
+    #[must_use]
+    pub const fn flood_fill(self, mask: Bitboard) -> Bitboard {
+        let mut filled = self.0 & mask.0;
+        loop {
+            let next = Bitboard(filled).dilate().0 & mask.0;
+            if next == filled {
+                return Bitboard(filled);
+            }
+            filled = next;
+        }
+    }
+
+    /// Returns the squares from the position, exclusive, to the edge of the
+    /// board in the specified direction.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Direction, Position};
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// let rank_1 = Bitboard::from(0xff00_0000_0000_0000);
+    /// assert_eq!(Bitboard::ray(a1, Direction::East), rank_1 ^ a1);
+    /// assert_eq!(Bitboard::ray(a1, Direction::North), Bitboard::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn ray(pos: Position, direction: Direction) -> Bitboard {
+        Bitboard(SHIFT_RAYS[pos.index() as usize][direction.index()])
+    }
+
+    /// Returns the squares strictly between two positions on the same rank,
+    /// file or diagonal, or an empty bitboard if they do not share a line.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// let d4 = Position::try_from("d4").unwrap();
+    /// let b2 = Position::try_from("b2").unwrap();
+    /// let c3 = Position::try_from("c3").unwrap();
+    /// assert_eq!(Bitboard::between(a1, d4), b2 | c3);
+    ///
+    /// let b3 = Position::try_from("b3").unwrap();
+    /// assert_eq!(Bitboard::between(a1, b3), Bitboard::EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn between(a: Position, b: Position) -> Bitboard {
+        let mut i = 0;
+        while i < Direction::ALL.len() {
+            let direction = Direction::ALL[i];
+            let ray = Bitboard::ray(a, direction).0;
+            if ray & b.raw() != 0 {
+                return Bitboard(ray & Bitboard::ray(b, direction.opposite()).0);
+            }
+            i += 1;
+        }
+        Bitboard::EMPTY
     }
 }
 
+/// An iterator over the positions of the set bits of a bitboard.
+///
+/// Created by [`Bitboard::hot_bits`] and its variants.
+///
+/// [`Bitboard::hot_bits`]: crate::othello::Bitboard::hot_bits
 #[derive(Clone, Debug)]
-struct HotBits {
-    remaining: u8,
+pub struct HotBits {
     bitboard: Bitboard,
 }
 
+impl Iterator for HotBits {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bitboard.pop_first()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl DoubleEndedIterator for HotBits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.bitboard.pop_last()
+    }
+}
+
+impl ExactSizeIterator for HotBits {
+    fn len(&self) -> usize {
+        self.bitboard.count_set().into()
+    }
+}
+
+impl FusedIterator for HotBits {}
+
+/// An iterator over all 64 squares of a bitboard, each as its own bitboard.
+///
+/// Created by [`Bitboard::bits`].
+///
+/// [`Bitboard::bits`]: crate::othello::Bitboard::bits
 #[derive(Clone, Debug)]
-struct HotBitsIntoIterator {
-    remaining: u8,
+pub struct Bits {
     bitboard: Bitboard,
+    // The squares in [front, back) remain, indexed from A1
+    front: u8,
+    back: u8,
 }
 
-impl IntoIterator for HotBits {
-    type Item = Position;
-    type IntoIter = HotBitsIntoIterator;
+impl Iterator for Bits {
+    type Item = Bitboard;
 
-    fn into_iter(self) -> Self::IntoIter {
-        HotBitsIntoIterator {
-            remaining: self.remaining,
-            bitboard: self.bitboard,
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
         }
+        let square = 1 << (63 - self.front);
+        self.front += 1;
+        Some(Bitboard(self.bitboard.0 & square))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
     }
 }
 
-impl Iterator for HotBitsIntoIterator {
-    type Item = Position;
+impl DoubleEndedIterator for Bits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let square = 1 << (63 - self.back);
+        Some(Bitboard(self.bitboard.0 & square))
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.bitboard.is_empty() {
-            None
+impl ExactSizeIterator for Bits {
+    fn len(&self) -> usize {
+        (self.back - self.front).into()
+    }
+}
+
+impl FusedIterator for Bits {}
+
+impl FromStr for Bitboard {
+    type Err = BitboardError;
+
+    /// Parses a bitboard from hexadecimal or from an 8x8 grid.
+    ///
+    /// Hexadecimal bitboards are prefixed with `0x`, such as
+    /// `0x0000001818000000`. Grids list the squares from A1 to H8, where
+    /// `1`, `X` or `#` is a set bit and `0`, `.` or `-` is an unset one.
+    /// Whitespace between squares is ignored, so both forms printed by
+    /// [`Display`] can be parsed back.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let hex: Bitboard = "0x0000001818000000".parse().unwrap();
+    /// let grid: Bitboard = "
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     ...11...
+    ///     ...11...
+    ///     ........
+    ///     ........
+    ///     ........
+    /// ".parse().unwrap();
+    /// assert_eq!(hex, grid);
+    /// assert_eq!(hex, Bitboard::CENTER);
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            // from_str_radix would also accept a leading sign
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(BitboardError::InvalidNotation);
+            }
+            return u64::from_str_radix(hex, 16)
+                .map(Bitboard)
+                .map_err(|_| BitboardError::InvalidNotation);
+        }
+        let mut bitboard = 0;
+        let mut squares = 0;
+        for c in text.chars().filter(|c| !c.is_whitespace()) {
+            let bit = match c {
+                '1' | 'X' | 'x' | '#' => 1,
+                '0' | '.' | '-' => 0,
+                _ => return Err(BitboardError::InvalidNotation),
+            };
+            if squares == 64 {
+                return Err(BitboardError::InvalidNotation);
+            }
+            bitboard = (bitboard << 1) | bit;
+            squares += 1;
+        }
+        if squares == 64 {
+            Ok(Bitboard(bitboard))
         } else {
-            self.remaining -= 1;
-            let position = 1 << (63 - self.bitboard.raw().leading_zeros());
-            self.bitboard ^= position;
+            Err(BitboardError::InvalidNotation)
+        }
+    }
+}
 
-            Some(Position::new_unchecked(position))
+impl fmt::Display for Bitboard {
+    /// Formats the bitboard as an 8x8 grid from A1 to H8, using `1` for set
+    /// bits and `.` for unset ones, or as hexadecimal with the alternate flag
+    /// (`{:#}`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let grid = "........\n\
+    ///             ........\n\
+    ///             ........\n\
+    ///             ...11...\n\
+    ///             ...11...\n\
+    ///             ........\n\
+    ///             ........\n\
+    ///             ........";
+    /// assert_eq!(Bitboard::CENTER.to_string(), grid);
+    /// assert_eq!(format!("{:#}", Bitboard::CENTER), "0x0000001818000000");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:#018x}", self.0);
+        }
+        for (index, bit) in self.bits().enumerate() {
+            if index > 0 && index % 8 == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", if bit.is_empty() { '.' } else { '1' })?;
         }
+        Ok(())
     }
 }
 
-impl ExactSizeIterator for HotBitsIntoIterator {
-    fn len(&self) -> usize {
-        self.remaining.into()
+/// This enum represents errors that may occur when parsing a [`Bitboard`].
+///
+/// [`Bitboard`]: crate::othello::Bitboard
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BitboardError {
+    /// Indicates that the text was neither hexadecimal nor an 8x8 grid.
+    InvalidNotation,
+}
+
+impl fmt::Display for BitboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNotation => write!(f, "invalid bitboard notation"),
+        }
     }
 }
 
+impl error::Error for BitboardError {}
+
 impl From<u64> for Bitboard {
     fn from(bitboard: u64) -> Self {
         Bitboard(bitboard)
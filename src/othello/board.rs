@@ -4,9 +4,11 @@ use crate::othello::{
         BLACK_START_POS, FILE_A, FILE_H, RANK_1, RANK_8, SHIFT_DIRS, SHIFT_MASKS, SHIFT_RAYS,
         WHITE_START_POS,
     },
-    display::BoardDisplay,
 };
-use std::{error, fmt};
+use core::{error, fmt};
+
+#[cfg(feature = "std")]
+use crate::othello::display::{self, BoardDisplay};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -66,7 +68,7 @@ impl Board {
     /// assert_eq!(64, board.empty_squares().count_set());
     /// ```
     #[must_use]
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         Self {
             black_stones: Bitboard::EMPTY,
             white_stones: Bitboard::EMPTY,
@@ -98,10 +100,10 @@ impl Board {
     /// assert_eq!(60, board.empty_squares().count_set());
     /// ```
     #[must_use]
-    pub fn standard() -> Self {
+    pub const fn standard() -> Self {
         Self {
-            black_stones: BLACK_START_POS.into(),
-            white_stones: WHITE_START_POS.into(),
+            black_stones: Bitboard(BLACK_START_POS),
+            white_stones: Bitboard(WHITE_START_POS),
         }
     }
 
@@ -125,8 +127,8 @@ impl Board {
     /// assert!(!board.is_valid());
     /// ```
     #[must_use]
-    pub fn is_valid(&self) -> bool {
-        self.black_stones & self.white_stones == 0
+    pub const fn is_valid(&self) -> bool {
+        self.black_stones.0 & self.white_stones.0 == 0
     }
 
     /// Places stones in the specified positions.
@@ -151,10 +153,10 @@ impl Board {
     /// board.place_stone_unchecked(Stone::Black, pos);
     /// assert_ne!(board, Board::empty());
     /// ```
-    pub fn place_stone_unchecked(&mut self, stone: Stone, pos: Bitboard) {
+    pub const fn place_stone_unchecked(&mut self, stone: Stone, pos: Bitboard) {
         match stone {
-            Stone::Black => self.black_stones |= pos,
-            Stone::White => self.white_stones |= pos,
+            Stone::Black => self.black_stones.0 |= pos.0,
+            Stone::White => self.white_stones.0 |= pos.0,
         }
     }
 
@@ -177,10 +179,10 @@ impl Board {
     /// board.remove_stone_unchecked(Stone::White, white_stones);
     /// assert_eq!(board, Board::empty());
     /// ```
-    pub fn remove_stone_unchecked(&mut self, stone: Stone, pos: Bitboard) {
+    pub const fn remove_stone_unchecked(&mut self, stone: Stone, pos: Bitboard) {
         match stone {
-            Stone::Black => self.black_stones &= !pos,
-            Stone::White => self.white_stones &= !pos,
+            Stone::Black => self.black_stones.0 &= !pos.0,
+            Stone::White => self.white_stones.0 &= !pos.0,
         }
     }
 
@@ -203,37 +205,40 @@ impl Board {
     /// board.play(Stone::Black, pos);
     /// assert_ne!(board, Board::standard());
     /// ```
-    pub fn play(&mut self, stone: Stone, pos: Position) {
-        let pos: Bitboard = pos.into();
-        let current_bits = self.bits_for(stone);
-        let opponent_bits = self.bits_for(stone.flip());
+    pub const fn play(&mut self, stone: Stone, pos: Position) {
+        let pos = pos.raw();
+        let current_bits = self.bits_for(stone).0;
+        let opponent_bits = self.bits_for(stone.flip()).0;
 
         let mut mask = 0;
-        for (i, shift) in SHIFT_DIRS.iter().enumerate() {
+        let mut i = 0;
+        while i < SHIFT_DIRS.len() {
+            let shift = SHIFT_DIRS[i];
             let mut dir_mask = 0;
-            let shift_mask = SHIFT_MASKS[i] & SHIFT_RAYS[pos.raw().leading_zeros() as usize][i];
+            let shift_mask = SHIFT_MASKS[i] & SHIFT_RAYS[pos.leading_zeros() as usize][i];
             let opponent_bits = opponent_bits & shift_mask;
 
             let mut current = pos;
             let mut next = current;
             while current != 0 {
                 dir_mask |= current;
-                next = dir_shift(current, *shift);
+                next = dir_shift(current, shift);
                 current = next & opponent_bits;
             }
             if next & current_bits != 0 {
                 mask |= dir_mask ^ pos;
             }
+            i += 1;
         }
 
         match stone {
             Stone::Black => {
-                self.black_stones |= mask | pos;
-                self.white_stones ^= mask;
+                self.black_stones.0 |= mask | pos;
+                self.white_stones.0 ^= mask;
             }
             Stone::White => {
-                self.white_stones |= mask | pos;
-                self.black_stones ^= mask;
+                self.white_stones.0 |= mask | pos;
+                self.black_stones.0 ^= mask;
             }
         }
     }
@@ -253,7 +258,7 @@ impl Board {
     /// assert_eq!(black.count_set(), white.count_set());
     /// ```
     #[must_use]
-    pub fn bits_for(&self, stone: Stone) -> Bitboard {
+    pub const fn bits_for(&self, stone: Stone) -> Bitboard {
         match stone {
             Stone::Black => self.black_stones,
             Stone::White => self.white_stones,
@@ -271,73 +276,73 @@ impl Board {
     /// assert!(!board.is_legal_move(Stone::Black, pos));
     /// ```
     #[must_use]
-    pub fn is_legal_move(&self, stone: Stone, pos: Position) -> bool {
-        let pos = Bitboard::from(pos);
-        let current_bits = self.bits_for(stone);
-        let opponent_bits = self.bits_for(stone.flip());
+    pub const fn is_legal_move(&self, stone: Stone, pos: Position) -> bool {
+        let pos = pos.raw();
+        let current_bits = self.bits_for(stone).0;
+        let opponent_bits = self.bits_for(stone.flip()).0;
 
         // Pos must be on an empty square to be legal
         if pos & (current_bits | opponent_bits) != 0 {
             return false;
         }
 
-        for (i, shift) in SHIFT_DIRS.iter().enumerate() {
+        let mut i = 0;
+        while i < SHIFT_DIRS.len() {
+            let shift = SHIFT_DIRS[i];
             let mut dir_mask = 0;
-            let shift_mask = SHIFT_MASKS[i] & SHIFT_RAYS[pos.raw().leading_zeros() as usize][i];
+            let shift_mask = SHIFT_MASKS[i] & SHIFT_RAYS[pos.leading_zeros() as usize][i];
             let opponent_bits = opponent_bits & shift_mask;
 
             let mut current = pos;
             let mut next = current;
             while current != 0 {
                 dir_mask |= current;
-                next = dir_shift(current, *shift);
+                next = dir_shift(current, shift);
                 current = next & opponent_bits;
             }
             if next & current_bits != 0 && dir_mask ^ pos != 0 {
                 return true;
             }
+            i += 1;
         }
         false
     }
 
     /// Calculates and returns the set of all legal moves for the specified player.
     ///
+    /// Like the other core queries, this can be evaluated at compile time.
+    ///
     /// # Examples
     /// ```rust
-    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::{Bitboard, Board, Stone};
     ///
     /// let board = Board::standard();
     /// let stone = Stone::Black;
     /// assert_eq!(4, board.moves_for(stone).count_set());
+    ///
+    /// const START: Board = Board::standard();
+    /// const MOVES: Bitboard = START.moves_for(Stone::Black);
+    /// assert_eq!(MOVES, board.moves_for(stone));
     /// ```
     #[must_use]
-    pub fn moves_for(&self, stone: Stone) -> Bitboard {
-        let current_bits = self.bits_for(stone);
-        let opponent_bits = self.bits_for(stone.flip());
-        let empty_squares = self.empty_squares();
-
-        let move_in_dir = |mask: u64, shift: i8| {
-            let excluded: Bitboard = opponent_bits & mask;
-            let mut m: Bitboard = dir_shift(current_bits, shift) & excluded;
-            m |= dir_shift(m, shift) & excluded;
-            m |= dir_shift(m, shift) & excluded;
-            m |= dir_shift(m, shift) & excluded;
-            m |= dir_shift(m, shift) & excluded;
-            m |= dir_shift(m, shift) & excluded;
-            dir_shift(m, shift) & empty_squares
-        };
-
-        let exclude_top_bottom = !(RANK_1 | RANK_8);
-        let exclude_left_right = !(FILE_A | FILE_H);
-
-        let mut moves = move_in_dir(exclude_top_bottom, -8);
-        moves |= move_in_dir(exclude_left_right, -7);
-        moves |= move_in_dir(exclude_left_right, 1);
-        moves |= move_in_dir(exclude_left_right, 9);
-        moves |= move_in_dir(exclude_top_bottom, 8);
-        moves |= move_in_dir(exclude_left_right, 7);
-        moves |= move_in_dir(exclude_left_right, -1);
-        moves | move_in_dir(exclude_left_right, -9)
+    pub const fn moves_for(&self, stone: Stone) -> Bitboard {
+        let current_bits = self.bits_for(stone).0;
+        let opponent_bits = self.bits_for(stone.flip()).0;
+        let empty_squares = self.empty_squares().0;
+
+        // Opponent stones on the edges can never be flipped in directions
+        // crossing those edges, which also prevents wrapping around the board
+        let vertical = opponent_bits & !(RANK_1 | RANK_8);
+        let horizontal = opponent_bits & !(FILE_A | FILE_H);
+
+        let mut moves = moves_in_dir(current_bits, vertical, empty_squares, -8);
+        moves |= moves_in_dir(current_bits, horizontal, empty_squares, -7);
+        moves |= moves_in_dir(current_bits, horizontal, empty_squares, 1);
+        moves |= moves_in_dir(current_bits, horizontal, empty_squares, 9);
+        moves |= moves_in_dir(current_bits, vertical, empty_squares, 8);
+        moves |= moves_in_dir(current_bits, horizontal, empty_squares, 7);
+        moves |= moves_in_dir(current_bits, horizontal, empty_squares, -1);
+        Bitboard(moves | moves_in_dir(current_bits, horizontal, empty_squares, -9))
     }
 
     /// Returns the set of all empty squares on the board.
@@ -350,8 +355,8 @@ impl Board {
     /// assert_eq!(60, board.empty_squares().count_set());
     /// ```
     #[must_use]
-    pub fn empty_squares(&self) -> Bitboard {
-        !(self.black_stones | self.white_stones)
+    pub const fn empty_squares(&self) -> Bitboard {
+        Bitboard(!(self.black_stones.0 | self.white_stones.0))
     }
 
     /// Queries the board at the specified position for the presence of a stone.
@@ -365,10 +370,10 @@ impl Board {
     /// assert_eq!(Some(Stone::White), board.stone_at(pos));
     ///  ```
     #[must_use]
-    pub fn stone_at(&self, pos: Position) -> Option<Stone> {
-        if self.black_stones & pos > 0 {
+    pub const fn stone_at(&self, pos: Position) -> Option<Stone> {
+        if self.black_stones.0 & pos.raw() != 0 {
             Some(Stone::Black)
-        } else if self.white_stones & pos > 0 {
+        } else if self.white_stones.0 & pos.raw() != 0 {
             Some(Stone::White)
         } else {
             None
@@ -377,7 +382,7 @@ impl Board {
 
     /// Returns a struct that implements [`Display`] for customizing the display of Othello boards.
     ///
-    /// [`Display`]: std::fmt::Display
+    /// [`Display`]: core::fmt::Display
     ///
     /// # Examples
     /// ```rust
@@ -386,10 +391,58 @@ impl Board {
     /// let board = Board::standard();
     /// println!("{}", board.display());
     ///  ```
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn display(&'_ self) -> BoardDisplay<'_> {
         BoardDisplay::new(self)
     }
+
+    /// Parses a board from a text diagram.
+    ///
+    /// The [`Compact`], [`Standard`] and [`Unicode`] output of
+    /// [`BoardDisplay`] are accepted, as well as plain grids of eight rows
+    /// with eight squares each. Black stones are written as `B` or `●`,
+    /// white stones as `W` or `○` and empty squares as `.`, `-` or a space.
+    /// Legal move markers (`*` and `·`) are treated as empty squares, and
+    /// highlighted squares may be surrounded by brackets or parentheses.
+    /// Coordinate labels, borders and surrounding whitespace are optional.
+    /// Diagrams printed from the perspective of White are recognized by
+    /// their reversed file labels.
+    ///
+    /// [`Compact`]: crate::othello::Format::Compact
+    /// [`Standard`]: crate::othello::Format::Standard
+    /// [`Unicode`]: crate::othello::Format::Unicode
+    /// [`BoardDisplay`]: crate::othello::BoardDisplay
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, Stone};
+    ///
+    /// let board = Board::from_diagram("
+    ///     ........
+    ///     ........
+    ///     ........
+    ///     ...WB...
+    ///     ...BW...
+    ///     ........
+    ///     ........
+    ///     ........
+    /// ").unwrap();
+    /// assert_eq!(board, Board::standard());
+    ///
+    /// // Printed boards can be parsed back
+    /// let diagram = board
+    ///     .display()
+    ///     .with_format(Format::Compact)
+    ///     .with_stone(Stone::Black)
+    ///     .to_string();
+    /// assert_eq!(Board::from_diagram(&diagram), Ok(board));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_diagram(diagram: &str) -> Result<Self, BoardError> {
+        let (black_stones, white_stones) = display::parse_diagram(diagram)?;
+        Board::try_from((black_stones, white_stones))
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Deserialize))]
@@ -400,7 +453,7 @@ struct ShadowBoard {
 }
 
 #[cfg(feature = "serde")]
-impl std::convert::TryFrom<ShadowBoard> for Board {
+impl TryFrom<ShadowBoard> for Board {
     type Error = BoardError;
 
     fn try_from(unchecked: ShadowBoard) -> Result<Self, Self::Error> {
@@ -484,23 +537,76 @@ impl TryFrom<(Bitboard, Bitboard)> for Board {
 }
 
 /// This enum represents errors that may occur when using a Othello board.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum BoardError {
     /// Indicates that the operation would have resulted in two or more stones overlapping.
     OverlappingPieces,
+    /// Indicates that a stone lies outside of the board.
+    OutOfBounds,
+    /// Indicates that a board diagram could not be parsed.
+    InvalidDiagram(DiagramError),
 }
 
 impl fmt::Display for BoardError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::OverlappingPieces => write!(f, "overlapping pieces"),
+            Self::OutOfBounds => write!(f, "stone outside of the board"),
+            Self::InvalidDiagram(e) => write!(f, "invalid diagram: {e}"),
         }
     }
 }
 
 impl error::Error for BoardError {}
 
+/// This enum describes why a board diagram could not be parsed by
+/// [`Board::from_diagram`].
+///
+/// [`Board::from_diagram`]: crate::othello::Board::from_diagram
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DiagramError {
+    /// The diagram has fewer than eight ranks. Holds the number of ranks
+    /// found.
+    RankCount(u8),
+    /// The diagram has more than eight ranks.
+    TooManyRanks,
+    /// The rank does not have eight squares.
+    SquareCount(u8),
+    /// The rank is labeled with another number.
+    RankLabel(u8),
+    /// The rank contains a square that is not a stone or empty.
+    InvalidSquare(u8),
+}
+
+impl fmt::Display for DiagramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RankCount(ranks) => write!(f, "expected 8 ranks, found {ranks}"),
+            Self::TooManyRanks => write!(f, "more than 8 ranks"),
+            Self::SquareCount(rank) => write!(f, "rank {rank} does not have 8 squares"),
+            Self::RankLabel(rank) => write!(f, "rank {rank} has the wrong label"),
+            Self::InvalidSquare(rank) => write!(f, "rank {rank} has an invalid square"),
+        }
+    }
+}
+
+impl error::Error for DiagramError {}
+
 // https://www.chessprogramming.org/General_Setwise_Operations#Generalized_Shift
-fn dir_shift(x: Bitboard, shift: i8) -> Bitboard {
+const fn dir_shift(x: u64, shift: i8) -> u64 {
     if shift > 0 { x >> shift } else { x << -shift }
 }
+
+/// Finds the moves capturing along a single direction, where the opponent
+/// bits are already masked to avoid wrapping around the board.
+const fn moves_in_dir(current_bits: u64, excluded: u64, empty_squares: u64, shift: i8) -> u64 {
+    let mut m = dir_shift(current_bits, shift) & excluded;
+    m |= dir_shift(m, shift) & excluded;
+    m |= dir_shift(m, shift) & excluded;
+    m |= dir_shift(m, shift) & excluded;
+    m |= dir_shift(m, shift) & excluded;
+    m |= dir_shift(m, shift) & excluded;
+    dir_shift(m, shift) & empty_squares
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The eight compass directions on a board.
+///
+/// Boards are printed with A1 in the top left corner, so north points
+/// towards rank 1 and east towards file H.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// Towards rank 1, e.g. from D4 to D3.
+    North,
+    /// Towards rank 1 and file H, e.g. from D4 to E3.
+    NorthEast,
+    /// Towards file H, e.g. from D4 to E4.
+    East,
+    /// Towards rank 8 and file H, e.g. from D4 to E5.
+    SouthEast,
+    /// Towards rank 8, e.g. from D4 to D5.
+    South,
+    /// Towards rank 8 and file A, e.g. from D4 to C5.
+    SouthWest,
+    /// Towards file A, e.g. from D4 to C4.
+    West,
+    /// Towards rank 1 and file A, e.g. from D4 to C3.
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from north.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Direction;
+    ///
+    /// assert_eq!(Direction::ALL.len(), 8);
+    /// assert_eq!(Direction::ALL[0], Direction::North);
+    /// ```
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the direction pointing the opposite way.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Direction;
+    ///
+    /// assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+    /// ```
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Direction::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Returns the index of the direction in [`ALL`], which is also the
+    /// order of the shift tables in the constants module.
+    ///
+    /// [`ALL`]: crate::othello::Direction::ALL
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
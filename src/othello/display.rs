@@ -1,14 +1,18 @@
 use crate::othello::{
-    Bitboard, Board, Position, Stone,
+    Bitboard, Board, BoardError, DiagramError, Position, Stone,
     constants::{FILES, RANKS},
 };
-use std::fmt;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Helper struct to customize the printing of Othello boards.
 ///
 /// Printing and thus visualizing the board is useful for both debugging. The
 /// output can be customized by choosing formatting options and whether or not
-/// legal moves should be shown for a specific player.
+/// legal moves should be shown for a specific player. The last move and the
+/// stones it flipped can be highlighted as well.
 ///
 /// # Examples
 /// ```rust
@@ -31,16 +35,103 @@ pub struct BoardDisplay<'a> {
     board: &'a Board,
     display: Format,
     stone: Option<Stone>,
+    last_move: Option<Position>,
+    flipped: Bitboard,
+    glyphs: Option<Glyphs>,
+    coordinates: bool,
+    perspective: Stone,
 }
 
 /// Represents the different formatting options available when displaying an
 /// Othello board.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Format {
     /// Formats the board compactly.
+    ///
+    /// This format has no room for highlighting the last move or the flipped
+    /// stones, which are therefore not shown.
     Compact,
     /// Standard formatting.
     Standard,
+    /// Formats the board with Unicode discs (● and ○) and box-drawing
+    /// borders.
+    Unicode,
+    /// Formats the board with Unicode discs on a green background, colored
+    /// with ANSI escape codes for terminals. The last move is shown on a
+    /// yellow background and flipped stones on a light green background.
+    Ansi,
+}
+
+impl Format {
+    /// Returns the characters this format prints squares with by default.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Format, Glyphs};
+    ///
+    /// let glyphs = Format::Compact.glyphs();
+    /// assert_eq!(glyphs.black, 'B');
+    /// assert_eq!(glyphs.empty, '.');
+    /// ```
+    #[must_use]
+    pub fn glyphs(self) -> Glyphs {
+        match self {
+            Format::Compact => Glyphs {
+                black: 'B',
+                white: 'W',
+                empty: '.',
+                legal_move: '*',
+            },
+            Format::Standard => Glyphs {
+                black: 'B',
+                white: 'W',
+                empty: ' ',
+                legal_move: '*',
+            },
+            Format::Unicode | Format::Ansi => Glyphs {
+                black: '●',
+                white: '○',
+                empty: ' ',
+                legal_move: '·',
+            },
+        }
+    }
+}
+
+/// The characters used to print the squares of a board.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Format, Glyphs};
+///
+/// let glyphs = Glyphs {
+///     black: 'X',
+///     white: 'O',
+///     ..Format::Compact.glyphs()
+/// };
+/// let board = Board::standard();
+/// let display = board.display().with_glyphs(glyphs).to_string();
+/// assert!(display.contains("| O | X |"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Glyphs {
+    /// The character of black stones.
+    pub black: char,
+    /// The character of white stones.
+    pub white: char,
+    /// The character of empty squares.
+    pub empty: char,
+    /// The character of empty squares that are legal moves.
+    pub legal_move: char,
+}
+
+/// The highlighting of a single square.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Highlight {
+    None,
+    LastMove,
+    Flipped,
 }
 
 impl<'a> BoardDisplay<'a> {
@@ -49,6 +140,11 @@ impl<'a> BoardDisplay<'a> {
             board,
             display: Format::Standard,
             stone: None,
+            last_move: None,
+            flipped: Bitboard::EMPTY,
+            glyphs: None,
+            coordinates: true,
+            perspective: Stone::Black,
         }
     }
 
@@ -64,9 +160,8 @@ impl<'a> BoardDisplay<'a> {
     #[must_use]
     pub fn with_stone(&self, stone: Stone) -> Self {
         Self {
-            board: self.board,
-            display: self.display,
             stone: Some(stone),
+            ..self.clone()
         }
     }
 
@@ -82,75 +177,690 @@ impl<'a> BoardDisplay<'a> {
     #[must_use]
     pub fn with_format(&self, display: Format) -> Self {
         Self {
-            board: self.board,
             display,
-            stone: self.stone,
+            ..self.clone()
+        }
+    }
+
+    /// Highlights the specified position as the last move played.
+    ///
+    /// In the [`Standard`] and [`Unicode`] formats the square is surrounded
+    /// by brackets, such as `[B]`.
+    ///
+    /// [`Standard`]: crate::othello::Format::Standard
+    /// [`Unicode`]: crate::othello::Format::Unicode
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, Position, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// let pos = Position::try_from("d3").unwrap();
+    /// board.play(Stone::Black, pos);
+    ///
+    /// let display = board.display().with_last_move(pos).to_string();
+    /// assert!(display.contains("[B]"));
+    /// ```
+    #[must_use]
+    pub fn with_last_move(&self, pos: Position) -> Self {
+        Self {
+            last_move: Some(pos),
+            ..self.clone()
+        }
+    }
+
+    /// Highlights the specified stones as flipped by the last move.
+    ///
+    /// In the [`Standard`] and [`Unicode`] formats the squares are
+    /// surrounded by parentheses, such as `(B)`. The flipped stones of a move
+    /// are the stones of the opponent before the move that belong to the
+    /// player after it.
+    ///
+    /// [`Standard`]: crate::othello::Format::Standard
+    /// [`Unicode`]: crate::othello::Format::Unicode
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, Position, Stone};
+    ///
+    /// let before = Board::standard();
+    /// let pos = Position::try_from("d3").unwrap();
+    /// let mut after = before.clone();
+    /// after.play(Stone::Black, pos);
+    ///
+    /// let flipped = before.bits_for(Stone::White) & after.bits_for(Stone::Black);
+    /// let display = after
+    ///     .display()
+    ///     .with_format(Format::Unicode)
+    ///     .with_last_move(pos)
+    ///     .with_flipped(flipped)
+    ///     .to_string();
+    /// assert!(display.contains("[●]"));
+    /// assert!(display.contains("(●)"));
+    /// ```
+    #[must_use]
+    pub fn with_flipped(&self, flipped: Bitboard) -> Self {
+        Self {
+            flipped,
+            ..self.clone()
+        }
+    }
+
+    /// Displays the board with the specified characters instead of the
+    /// default ones of the format.
+    ///
+    /// Diagrams printed with custom characters can generally not be parsed
+    /// by [`Board::from_diagram`].
+    ///
+    /// [`Board::from_diagram`]: crate::othello::Board::from_diagram
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, Glyphs, Stone};
+    ///
+    /// let glyphs = Glyphs {
+    ///     legal_move: '+',
+    ///     ..Format::Compact.glyphs()
+    /// };
+    /// let board = Board::standard();
+    /// let display = board
+    ///     .display()
+    ///     .with_format(Format::Compact)
+    ///     .with_stone(Stone::Black)
+    ///     .with_glyphs(glyphs)
+    ///     .to_string();
+    /// assert!(display.contains("|...+....|"));
+    /// ```
+    #[must_use]
+    pub fn with_glyphs(&self, glyphs: Glyphs) -> Self {
+        Self {
+            glyphs: Some(glyphs),
+            ..self.clone()
+        }
+    }
+
+    /// Displays the board with or without the file and rank labels.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format};
+    ///
+    /// let board = Board::standard();
+    /// let display = board
+    ///     .display()
+    ///     .with_format(Format::Compact)
+    ///     .with_coordinates(false)
+    ///     .to_string();
+    /// assert_eq!(display.lines().next(), Some("+--------+"));
+    /// assert_eq!(display.lines().nth(4), Some("|...WB...|"));
+    /// ```
+    #[must_use]
+    pub fn with_coordinates(&self, coordinates: bool) -> Self {
+        Self {
+            coordinates,
+            ..self.clone()
+        }
+    }
+
+    /// Displays the board from the perspective of the specified player.
+    ///
+    /// Boards are printed from the perspective of Black by default, with A1
+    /// in the top left corner. From the perspective of White the board is
+    /// rotated half a turn, putting H8 in the top left corner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let display = board
+    ///     .display()
+    ///     .with_format(Format::Compact)
+    ///     .with_perspective(Stone::White)
+    ///     .to_string();
+    /// assert_eq!(display.lines().next(), Some("   HGFEDCBA"));
+    /// assert_eq!(display.lines().nth(2), Some("8 |........|"));
+    /// ```
+    #[must_use]
+    pub fn with_perspective(&self, stone: Stone) -> Self {
+        Self {
+            perspective: stone,
+            ..self.clone()
+        }
+    }
+
+    /// Prints several boards next to each other, separated by the specified
+    /// number of spaces, such as a position before and after a move.
+    ///
+    /// Every board keeps its own options, and boards of different heights
+    /// are aligned at the top.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, BoardDisplay, Format, Position, Stone};
+    ///
+    /// let before = Board::standard();
+    /// let mut after = before.clone();
+    /// let pos = Position::try_from("d3").unwrap();
+    /// after.play(Stone::Black, pos);
+    ///
+    /// let output = BoardDisplay::side_by_side(
+    ///     &[
+    ///         before.display().with_format(Format::Compact),
+    ///         after.display().with_format(Format::Compact),
+    ///     ],
+    ///     4,
+    /// );
+    /// assert_eq!(output.lines().nth(5), Some("4 |...WB...|    4 |...BB...|"));
+    /// ```
+    #[must_use]
+    pub fn side_by_side(displays: &[BoardDisplay], gap: usize) -> String {
+        let printed: Vec<String> = displays.iter().map(ToString::to_string).collect();
+        let widths: Vec<usize> = printed
+            .iter()
+            .map(|text| text.lines().map(visible_width).max().unwrap_or(0))
+            .collect();
+        let height = printed.iter().map(|text| text.lines().count()).max();
+
+        let mut output = String::new();
+        for row in 0..height.unwrap_or(0) {
+            let mut line = String::new();
+            for (index, (text, width)) in printed.iter().zip(&widths).enumerate() {
+                if index > 0 {
+                    line.push_str(&" ".repeat(gap));
+                }
+                let part = text.lines().nth(row).unwrap_or("");
+                line.push_str(part);
+                line.push_str(&" ".repeat(width - visible_width(part)));
+            }
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        output
+    }
+
+    fn layout(&self) -> Layout {
+        Layout {
+            coordinates: self.coordinates,
+            rotated: self.perspective == Stone::White,
+        }
+    }
+
+    fn highlight(&self, pos: Position) -> Highlight {
+        if self.last_move == Some(pos) {
+            Highlight::LastMove
+        } else if self.flipped & pos > 0 {
+            Highlight::Flipped
+        } else {
+            Highlight::None
         }
     }
 }
 
 impl fmt::Display for BoardDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        display(f, self.board, self.stone, self.display)
+        display(f, self)
     }
 }
 
-fn display(
-    f: &mut fmt::Formatter,
-    board: &Board,
-    stone: Option<Stone>,
+/// Prints the classic "numbered moves" diagram of a game, where every square
+/// shows the number of the move that placed a stone on it.
+///
+/// Squares that held a stone before the first move show that stone instead.
+/// The diagram is printed with the same coordinates as [`BoardDisplay`], in
+/// the [`Compact`], [`Standard`] or [`Unicode`] format. The [`Ansi`] format
+/// is printed as [`Unicode`], without colors.
+///
+/// Game records can be turned into diagrams with [`GgfGame::move_diagram`].
+///
+/// [`BoardDisplay`]: crate::othello::BoardDisplay
+/// [`Compact`]: crate::othello::Format::Compact
+/// [`Standard`]: crate::othello::Format::Standard
+/// [`Unicode`]: crate::othello::Format::Unicode
+/// [`Ansi`]: crate::othello::Format::Ansi
+/// [`GgfGame::move_diagram`]: crate::ggf::GgfGame::move_diagram
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Format, MoveDiagram, Position};
+///
+/// let moves = ["f5", "d6", "c3"].map(|notation| Position::try_from(notation).unwrap());
+/// let diagram = MoveDiagram::new(&Board::standard(), moves)
+///     .with_format(Format::Compact)
+///     .to_string();
+/// assert!(diagram.contains("5   .  .  .  B  W  1  .  ."));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MoveDiagram {
+    start: Board,
+    numbers: [Option<u8>; 64],
     display: Format,
-) -> fmt::Result {
-    let legal_moves = stone.map_or(Bitboard::EMPTY, |stone| board.moves_for(stone));
-    let char_at = |rank: usize, file: usize| {
-        let pos = RANKS[rank] & FILES[file];
-        let pos = Position::new_unchecked(pos);
-        board
-            .stone_at(pos)
-            .map(|stone| match stone {
-                Stone::White => "W",
-                Stone::Black => "B",
-            })
-            .or({
-                if legal_moves & pos > 0 {
-                    Some("*")
-                } else {
-                    None
+}
+
+impl MoveDiagram {
+    /// Creates a diagram of the moves played in order from the starting
+    /// board, numbered from 1.
+    ///
+    /// The moves are not validated. If a square is played more than once,
+    /// the last number is shown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, MoveDiagram, Position};
+    ///
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// let diagram = MoveDiagram::new(&Board::standard(), [f5]);
+    /// assert_eq!(diagram.number_at(f5), Some(1));
+    /// ```
+    #[must_use]
+    pub fn new(start: &Board, moves: impl IntoIterator<Item = Position>) -> Self {
+        let mut numbers = [None; 64];
+        for (number, pos) in (1..=u8::MAX).zip(moves) {
+            numbers[pos.raw().leading_zeros() as usize] = Some(number);
+        }
+        Self {
+            start: start.clone(),
+            numbers,
+            display: Format::Standard,
+        }
+    }
+
+    /// Returns the number of the move played at the specified position, if
+    /// any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, MoveDiagram, Position};
+    ///
+    /// let d4 = Position::try_from("d4").unwrap();
+    /// let diagram = MoveDiagram::new(&Board::standard(), []);
+    /// assert_eq!(diagram.number_at(d4), None);
+    /// ```
+    #[must_use]
+    pub fn number_at(&self, pos: Position) -> Option<u8> {
+        self.numbers[pos.raw().leading_zeros() as usize]
+    }
+
+    /// Prints the diagram with the specified formatting.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Format, MoveDiagram};
+    ///
+    /// let diagram = MoveDiagram::new(&Board::standard(), []);
+    /// println!("{}", diagram.with_format(Format::Unicode));
+    /// ```
+    #[must_use]
+    pub fn with_format(mut self, display: Format) -> Self {
+        self.display = display;
+        self
+    }
+}
+
+impl fmt::Display for MoveDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let layout = Layout::STANDARD;
+        let glyphs = self.display.glyphs();
+        let label_at = |pos: Position| match (self.number_at(pos), self.start.stone_at(pos)) {
+            (Some(number), _) => number.to_string(),
+            (None, Some(Stone::Black)) => glyphs.black.to_string(),
+            (None, Some(Stone::White)) => glyphs.white.to_string(),
+            (None, None) => String::new(),
+        };
+
+        match self.display {
+            Format::Compact => {
+                write!(f, "  ")?;
+                for col in 0..8 {
+                    write!(f, "{:>3}", layout.file(col))?;
                 }
-            })
-            .unwrap_or(match display {
-                Format::Compact => ".",
-                Format::Standard => " ",
-            })
+                writeln!(f)?;
+                for row in 0..8 {
+                    layout.write_label(f, row)?;
+                    for col in 0..8 {
+                        let label = label_at(layout.pos_at(row, col));
+                        let label = if label.is_empty() { "." } else { &label };
+                        write!(f, "{label:>3}")?;
+                    }
+                    writeln!(f)?;
+                }
+                Ok(())
+            }
+            Format::Standard => grid(f, layout, false, |pos| format!("{:^3}", label_at(pos))),
+            Format::Unicode | Format::Ansi => {
+                grid(f, layout, true, |pos| format!("{:^3}", label_at(pos)))
+            }
+        }
+    }
+}
+
+/// How the rows and columns of a printed board map to its squares.
+#[derive(Clone, Copy)]
+struct Layout {
+    coordinates: bool,
+    rotated: bool,
+}
+
+impl Layout {
+    /// The layout of a board from the perspective of Black, with
+    /// coordinates.
+    const STANDARD: Layout = Layout {
+        coordinates: true,
+        rotated: false,
     };
 
-    match display {
-        Format::Compact => {
-            writeln!(f, "   ABCDEFGH")?;
-            writeln!(f, "  +--------+")?;
-            for rank in 0..8 {
-                write!(f, "{} |", rank + 1)?;
-                for file in 0..8 {
-                    write!(f, "{}", char_at(rank, file))?;
+    fn pos_at(self, row: usize, col: usize) -> Position {
+        let (rank, file) = if self.rotated {
+            (7 - row, 7 - col)
+        } else {
+            (row, col)
+        };
+        Position::new_unchecked(RANKS[rank] & FILES[file])
+    }
+
+    /// Returns the label of the file printed in the specified column.
+    fn file(self, col: usize) -> char {
+        let file = if self.rotated { 7 - col } else { col };
+        char::from(b'A' + u8::try_from(file).expect("files are below 8"))
+    }
+
+    /// Returns the label of the rank printed in the specified row.
+    fn rank(self, row: usize) -> usize {
+        if self.rotated { 8 - row } else { row + 1 }
+    }
+
+    /// Writes the row label and the separating space, if coordinates are
+    /// shown.
+    fn write_label(self, f: &mut fmt::Formatter, row: usize) -> fmt::Result {
+        if !self.coordinates {
+            return Ok(());
+        }
+        write!(f, "{} ", self.rank(row))
+    }
+
+    /// Returns the padding in front of lines without a row label.
+    fn margin(self) -> &'static str {
+        if self.coordinates { "  " } else { "" }
+    }
+}
+
+fn display(f: &mut fmt::Formatter, options: &BoardDisplay) -> fmt::Result {
+    let board = options.board;
+    let layout = options.layout();
+    let glyphs = options.glyphs.unwrap_or(options.display.glyphs());
+    let legal_moves = options
+        .stone
+        .map_or(Bitboard::EMPTY, |stone| board.moves_for(stone));
+    let char_at = |pos: Position| match board.stone_at(pos) {
+        Some(Stone::Black) => glyphs.black,
+        Some(Stone::White) => glyphs.white,
+        None if legal_moves & pos > 0 => glyphs.legal_move,
+        None => glyphs.empty,
+    };
+    // Surrounds a padded square with brackets or parentheses if highlighted
+    let cell_at = |pos: Position| {
+        let c = char_at(pos);
+        match options.highlight(pos) {
+            Highlight::None => format!(" {c} "),
+            Highlight::LastMove => format!("[{c}]"),
+            Highlight::Flipped => format!("({c})"),
+        }
+    };
+
+    match options.display {
+        Format::Compact => write_compact(
+            f,
+            8,
+            layout.coordinates,
+            |col| layout.file(col),
+            |row| layout.rank(row),
+            |row, col| char_at(layout.pos_at(row, col)),
+        ),
+        Format::Standard => grid(f, layout, false, cell_at),
+        Format::Unicode => grid(f, layout, true, cell_at),
+        Format::Ansi => {
+            const RESET: &str = "\x1b[0m";
+            const BOARD: &str = "\x1b[42m";
+
+            if layout.coordinates {
+                write!(f, "  ")?;
+                for col in 0..8 {
+                    write!(f, " {}", layout.file(col))?;
                 }
-                writeln!(f, "|")?;
+                writeln!(f)?;
             }
-            writeln!(f, "  +--------+")
-        }
-        Format::Standard => {
-            let top_row = "    A   B   C   D   E   F   G   H";
-            let horizontal = format!("  +{}", "---+".repeat(8));
-
-            writeln!(f, "{top_row}")?;
-            for rank in 0..8 {
-                writeln!(f, "{horizontal}")?;
-                write!(f, "{} |", rank + 1)?;
-                for file in 0..8 {
-                    write!(f, " {} |", char_at(rank, file))?;
+            for row in 0..8 {
+                layout.write_label(f, row)?;
+                for col in 0..8 {
+                    let pos = layout.pos_at(row, col);
+                    let background = match options.highlight(pos) {
+                        Highlight::None => 42,
+                        Highlight::LastMove => 43,
+                        Highlight::Flipped => 102,
+                    };
+                    let foreground = match board.stone_at(pos) {
+                        Some(Stone::Black) => 30,
+                        Some(Stone::White) => 97,
+                        None => 33,
+                    };
+                    write!(f, "\x1b[{background};{foreground}m {}", char_at(pos))?;
                 }
-                writeln!(f)?;
+                writeln!(f, "{BOARD} {RESET}")?;
             }
-            writeln!(f, "{horizontal}")
+            Ok(())
         }
     }
 }
+
+/// Writes a grid of squares padded to three characters, using either ASCII
+/// or box-drawing borders.
+fn grid(
+    f: &mut fmt::Formatter,
+    layout: Layout,
+    unicode: bool,
+    cell_at: impl Fn(Position) -> String,
+) -> fmt::Result {
+    let margin = layout.margin();
+    let (vertical, top, middle, bottom) = if unicode {
+        let border = |left: &str, middle: &str, right: &str| {
+            format!(
+                "{margin}{left}{}───{right}",
+                format!("───{middle}").repeat(7)
+            )
+        };
+        (
+            "│",
+            border("┌", "┬", "┐"),
+            border("├", "┼", "┤"),
+            border("└", "┴", "┘"),
+        )
+    } else {
+        let horizontal = format!("{margin}+{}", "---+".repeat(8));
+        ("|", horizontal.clone(), horizontal.clone(), horizontal)
+    };
+
+    if layout.coordinates {
+        let files: Vec<String> = (0..8).map(|col| layout.file(col).to_string()).collect();
+        writeln!(f, "    {}", files.join("   "))?;
+    }
+    writeln!(f, "{top}")?;
+    for row in 0..8 {
+        if row > 0 {
+            writeln!(f, "{middle}")?;
+        }
+        layout.write_label(f, row)?;
+        write!(f, "{vertical}")?;
+        for col in 0..8 {
+            write!(f, "{}{vertical}", cell_at(layout.pos_at(row, col)))?;
+        }
+        writeln!(f)?;
+    }
+    writeln!(f, "{bottom}")
+}
+
+/// Writes a board of any size in the compact format. The closures return
+/// the label of a printed column, the label of a printed row and the
+/// character of the square printed at a row and column.
+pub(crate) fn write_compact(
+    f: &mut fmt::Formatter,
+    size: usize,
+    coordinates: bool,
+    file: impl Fn(usize) -> char,
+    rank: impl Fn(usize) -> usize,
+    square: impl Fn(usize, usize) -> char,
+) -> fmt::Result {
+    // Rank labels are aligned to the right, so boards with ten or more rows
+    // need wider margins
+    let width = if coordinates {
+        size.to_string().len() + 1
+    } else {
+        0
+    };
+    if coordinates {
+        let files: String = (0..size).map(file).collect();
+        writeln!(f, "{:width$} {files}", "")?;
+    }
+    let border = format!("{:width$}+{}+", "", "-".repeat(size));
+    writeln!(f, "{border}")?;
+    for row in 0..size {
+        if coordinates {
+            write!(f, "{:>1$} ", rank(row), width - 1)?;
+        }
+        write!(f, "|")?;
+        for col in 0..size {
+            write!(f, "{}", square(row, col))?;
+        }
+        writeln!(f, "|")?;
+    }
+    writeln!(f, "{border}")
+}
+
+/// Counts the characters of a line that are visible in a terminal, skipping
+/// ANSI escape codes.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Escape codes end with a letter, such as the m of colors
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
+/// Parses a diagram as printed by [`display`], or a plain grid, into the
+/// black and white bitboards.
+pub(crate) fn parse_diagram(diagram: &str) -> Result<(u64, u64), BoardError> {
+    let invalid = BoardError::InvalidDiagram;
+    let mut black = 0;
+    let mut white = 0;
+    let mut row: u8 = 0;
+    // Diagrams printed from the perspective of White are rotated half a
+    // turn, which is recognized by their reversed file labels
+    let mut rotated = false;
+
+    for line in diagram.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with(['+', '┌', '├', '└']) {
+            continue;
+        }
+        if let Some(reversed) = header(line) {
+            rotated = reversed;
+            continue;
+        }
+        let line = &line.replace('│', "|");
+        if row == 8 {
+            return Err(invalid(DiagramError::TooManyRanks));
+        }
+        let rank = if rotated { 8 - row } else { row + 1 };
+        let squares = squares(line, rank).map_err(invalid)?;
+        if squares.len() != 8 {
+            return Err(invalid(DiagramError::SquareCount(rank)));
+        }
+        for (file, square) in squares.into_iter().enumerate() {
+            let bit = RANKS[usize::from(row)] & FILES[file];
+            match square {
+                Some(Stone::Black) => black |= bit,
+                Some(Stone::White) => white |= bit,
+                None => {}
+            }
+        }
+        row += 1;
+    }
+
+    if row != 8 {
+        Err(invalid(DiagramError::RankCount(row)))
+    } else if rotated {
+        // Reversing the bits rotates the board half a turn
+        Ok((black.reverse_bits(), white.reverse_bits()))
+    } else {
+        Ok((black, white))
+    }
+}
+
+/// Checks if the line only contains the file labels A to H, and returns
+/// whether they are reversed.
+fn header(line: &str) -> Option<bool> {
+    let files: String = line.split_whitespace().collect();
+    if files.eq_ignore_ascii_case("ABCDEFGH") {
+        Some(false)
+    } else if files.eq_ignore_ascii_case("HGFEDCBA") {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Parses the squares of a single rank, which may be surrounded by a rank
+/// label and borders.
+fn squares(line: &str, rank: u8) -> Result<Vec<Option<Stone>>, DiagramError> {
+    let square = |c| square(c, rank);
+    let Some((label, rest)) = line.split_once('|') else {
+        return line
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(square)
+            .collect();
+    };
+    let label = label.trim();
+    if !label.is_empty() && label != rank.to_string() {
+        return Err(DiagramError::RankLabel(rank));
+    }
+    let inner = rest.strip_suffix('|').unwrap_or(rest);
+    if inner.contains('|') {
+        // Standard format, where every square is padded and separated
+        inner
+            .split('|')
+            .map(|cell| {
+                let cell = cell.trim();
+                // Highlighted squares are surrounded by brackets or parentheses
+                let cell = cell
+                    .strip_prefix('[')
+                    .and_then(|cell| cell.strip_suffix(']'))
+                    .or_else(|| cell.strip_prefix('(')?.strip_suffix(')'))
+                    .map_or(cell, str::trim);
+                let mut chars = cell.chars();
+                match (chars.next(), chars.next()) {
+                    (None, _) => Ok(None),
+                    (Some(c), None) => square(c),
+                    _ => Err(DiagramError::InvalidSquare(rank)),
+                }
+            })
+            .collect()
+    } else {
+        inner.chars().map(square).collect()
+    }
+}
+
+/// Parses a single square on the specified rank, which is either a stone
+/// or empty.
+fn square(c: char, rank: u8) -> Result<Option<Stone>, DiagramError> {
+    match c {
+        'B' | 'b' | '●' => Ok(Some(Stone::Black)),
+        'W' | 'w' | '○' => Ok(Some(Stone::White)),
+        '.' | '-' | '*' | '·' | ' ' => Ok(None),
+        _ => Err(DiagramError::InvalidSquare(rank)),
+    }
+}
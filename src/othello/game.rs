@@ -1,14 +1,23 @@
-use crate::othello::{Bitboard, Board, BoardDisplay, BoardError, Position, Stone};
-use std::{cmp::Ordering, error, fmt};
+use crate::othello::{Action, Bitboard, Board, BoardError, Position, Score, ScoringRule, Stone};
+use core::{
+    error, fmt,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "std")]
+use crate::othello::BoardDisplay;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// This enum represents errors that may occur when playing an Othello game.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum GameError {
     /// Indicates that an illegal move was attempted.
     IllegalMove,
+    /// Indicates that a player attempted to pass despite having legal moves.
+    IllegalPass,
     /// The provided board is invalid.
     InvalidBoard(BoardError),
 }
@@ -28,6 +37,7 @@ impl fmt::Display for GameError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::IllegalMove => write!(f, "illegal move"),
+            Self::IllegalPass => write!(f, "illegal pass"),
             Self::InvalidBoard(_) => write!(f, "invalid board"),
         }
     }
@@ -36,25 +46,158 @@ impl fmt::Display for GameError {
 impl error::Error for GameError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
-            Self::IllegalMove => None,
+            Self::IllegalMove | Self::IllegalPass => None,
             Self::InvalidBoard(e) => Some(e),
         }
     }
 }
 
+/// A board that a [`Game`] can be played on.
+///
+/// The board finds and plays moves, while the game built on top of it takes
+/// care of turns, passes and the outcome. This is implemented by the
+/// standard [`Board`] and, with the `std` feature, by the boards of other
+/// sizes in the `variant` module.
+///
+/// [`Game`]: crate::othello::Game
+/// [`Board`]: crate::othello::Board
+pub trait GameBoard: Clone {
+    /// A set of squares, where the default value is the empty set.
+    type Squares: Copy + Default + Eq;
+    /// A single square.
+    type Square: Copy;
+
+    /// Returns the board of the standard opening position.
+    fn standard() -> Self;
+
+    /// Checks that no square is occupied by both players, and that every
+    /// stone lies on the board.
+    fn is_valid(&self) -> bool;
+
+    /// Returns the squares occupied by the specified player.
+    fn bits_for(&self, stone: Stone) -> Self::Squares;
+
+    /// Returns the squares without a stone.
+    fn empty_squares(&self) -> Self::Squares;
+
+    /// Returns the stone on the square, if any.
+    fn stone_at(&self, square: Self::Square) -> Option<Stone>;
+
+    /// Returns the legal moves of the specified player.
+    fn moves_for(&self, stone: Stone) -> Self::Squares;
+
+    /// Checks if the specified player may place a stone on the square.
+    fn is_legal_move(&self, stone: Stone, square: Self::Square) -> bool;
+
+    /// Places a stone on the square and flips all outflanked stones, without
+    /// checking whether the move is legal.
+    fn play(&mut self, stone: Stone, square: Self::Square);
+
+    /// Counts the score of the board according to the rule.
+    fn score(&self, rule: ScoringRule) -> Score;
+
+    /// Returns an iterator over the squares of a set.
+    fn squares(squares: Self::Squares) -> impl ExactSizeIterator<Item = Self::Square>;
+}
+
+impl GameBoard for Board {
+    type Squares = Bitboard;
+    type Square = Position;
+
+    fn standard() -> Self {
+        Board::standard()
+    }
+
+    fn is_valid(&self) -> bool {
+        Board::is_valid(self)
+    }
+
+    fn bits_for(&self, stone: Stone) -> Bitboard {
+        Board::bits_for(self, stone)
+    }
+
+    fn empty_squares(&self) -> Bitboard {
+        Board::empty_squares(self)
+    }
+
+    fn stone_at(&self, pos: Position) -> Option<Stone> {
+        Board::stone_at(self, pos)
+    }
+
+    fn moves_for(&self, stone: Stone) -> Bitboard {
+        Board::moves_for(self, stone)
+    }
+
+    fn is_legal_move(&self, stone: Stone, pos: Position) -> bool {
+        Board::is_legal_move(self, stone, pos)
+    }
+
+    fn play(&mut self, stone: Stone, pos: Position) {
+        Board::play(self, stone, pos);
+    }
+
+    fn score(&self, rule: ScoringRule) -> Score {
+        Score::new(self, rule)
+    }
+
+    fn squares(squares: Bitboard) -> impl ExactSizeIterator<Item = Position> {
+        squares.hot_bits()
+    }
+}
+
 /// Represents an Othello game.
 ///
 /// To interact with the game it is useful to understand the bitboards that
 /// the board uses. The [`Board`]-struct documents these.
+///
+/// Games are played on the standard [`Board`] by default, but any
+/// [`GameBoard`] will do. Constructors that cannot infer the board from
+/// their arguments, such as [`new`], are only available for the standard
+/// board, while [`standard`] works for all of them.
+///
+/// [`GameBoard`]: crate::othello::GameBoard
+/// [`new`]: crate::othello::Game::new
+/// [`standard`]: crate::othello::Game::standard
+///
+/// Equality and hashing only consider the position, that is the board, the
+/// player to move and whether the last turn was passed. Settings such as
+/// [automatic passing] and [enforced passes] are left out, and so is their
+/// serialization.
+///
+/// [automatic passing]: crate::othello::Game::with_auto_pass
+/// [enforced passes]: crate::othello::Game::with_enforced_passes
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Game {
-    board: Board,
+#[derive(Clone, Debug)]
+pub struct Game<B = Board> {
+    board: B,
     next_player: Stone,
     passed_last_turn: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    auto_pass: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    enforce_passes: bool,
+}
+
+impl<B: PartialEq> PartialEq for Game<B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board
+            && self.next_player == other.next_player
+            && self.passed_last_turn == other.passed_last_turn
+    }
+}
+
+impl<B: Eq> Eq for Game<B> {}
+
+impl<B: Hash> Hash for Game<B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.board.hash(state);
+        self.next_player.hash(state);
+        self.passed_last_turn.hash(state);
+    }
 }
 
 /// This enum represents all states the game can be in.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Status {
     /// Indicates that the game has concluded with the specified winner.
@@ -92,7 +235,45 @@ impl Game {
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        Game::from_state(Board::standard(), Stone::Black, false).unwrap()
+        Game::standard()
+    }
+
+    /// Returns a struct that implements [`Display`] for customizing the display of Othello boards.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Stone};
+    ///
+    /// let game = Game::new();
+    /// println!("{}", game.display());
+    ///  ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn display(&'_ self) -> BoardDisplay<'_> {
+        self.board.display()
+    }
+}
+
+impl<B: GameBoard> Game<B> {
+    /// Returns a game with the standard opening position of the board
+    /// configured and Black to move.
+    ///
+    /// For the standard board this is the same as [`new`], which does not
+    /// need the type of the board spelled out.
+    ///
+    /// [`new`]: crate::othello::Game::new
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game};
+    ///
+    /// assert_eq!(Game::<Board>::standard(), Game::new());
+    /// ```
+    #[must_use]
+    pub fn standard() -> Self {
+        Game::from_state(B::standard(), Stone::Black, false).expect("the standard board is valid")
     }
 
     /// Returns a game with the specified parameters set.
@@ -110,7 +291,7 @@ impl Game {
     /// assert_eq!(game.board(), Board::empty());
     /// ```
     pub fn from_state(
-        board: Board,
+        board: B,
         next_player: Stone,
         passed_last_turn: bool,
     ) -> Result<Self, GameError> {
@@ -119,12 +300,107 @@ impl Game {
                 board,
                 next_player,
                 passed_last_turn,
+                auto_pass: false,
+                enforce_passes: false,
             })
         } else {
             Err(BoardError::OverlappingPieces.into())
         }
     }
 
+    /// Enables or disables automatic passing.
+    ///
+    /// With automatic passing enabled, a successful [`play`] is followed by a
+    /// pass whenever the next player has no legal moves but the game is not
+    /// over yet, so the current player always has a move to make until the
+    /// game concludes. Passes made this way are reported by
+    /// [`passed_last_turn`].
+    ///
+    /// Automatic passing is disabled by default.
+    ///
+    /// [`play`]: crate::othello::Game::play
+    /// [`passed_last_turn`]: crate::othello::Game::passed_last_turn
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, Stone};
+    ///
+    /// // After Black plays H1, White's only stone is stuck on B8 while Black
+    /// // can still play C8
+    /// let black = 0x04_00_00_00_00_00_00_80;
+    /// let white = 0x02_00_00_00_00_00_00_40;
+    /// let board = Board::try_from((black, white)).unwrap();
+    /// let mut game = Game::from_state(board, Stone::Black, false)
+    ///     .unwrap()
+    ///     .with_auto_pass(true);
+    ///
+    /// game.play("h1".try_into().unwrap()).unwrap();
+    /// assert!(game.auto_pass());
+    /// assert_eq!(game.current_turn(), Stone::Black);
+    /// assert!(game.passed_last_turn());
+    /// ```
+    #[must_use]
+    pub fn with_auto_pass(mut self, auto_pass: bool) -> Self {
+        self.auto_pass = auto_pass;
+        self
+    }
+
+    /// Returns whether or not forced passes are made automatically.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// assert!(!Game::new().auto_pass());
+    /// assert!(Game::new().with_auto_pass(true).auto_pass());
+    /// ```
+    #[must_use]
+    pub fn auto_pass(&self) -> bool {
+        self.auto_pass
+    }
+
+    /// Enables or disables the enforcement of legal passes.
+    ///
+    /// With enforcement enabled, [`apply`] rejects passes made while the
+    /// current player has legal moves, exactly like [`pass`] does. This is
+    /// useful to catch buggy agents. The unchecked [`pass_turn`] remains
+    /// available for setting up positions during analysis.
+    ///
+    /// Enforcement is disabled by default.
+    ///
+    /// [`apply`]: crate::othello::Game::apply
+    /// [`pass`]: crate::othello::Game::pass
+    /// [`pass_turn`]: crate::othello::Game::pass_turn
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Action, Game, GameError};
+    ///
+    /// let mut game = Game::new().with_enforced_passes(true);
+    /// assert_eq!(game.apply(Action::Pass), Err(GameError::IllegalPass));
+    /// ```
+    #[must_use]
+    pub fn with_enforced_passes(mut self, enforce_passes: bool) -> Self {
+        self.enforce_passes = enforce_passes;
+        self
+    }
+
+    /// Returns whether or not [`apply`] rejects illegal passes.
+    ///
+    /// [`apply`]: crate::othello::Game::apply
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// assert!(!Game::new().enforces_passes());
+    /// assert!(Game::new().with_enforced_passes(true).enforces_passes());
+    /// ```
+    #[must_use]
+    pub fn enforces_passes(&self) -> bool {
+        self.enforce_passes
+    }
+
     /// Returns the stone of the current player.
     ///
     /// # Examples
@@ -139,12 +415,44 @@ impl Game {
         self.next_player
     }
 
-    /// The current player will pass their turn.
+    /// The current player will pass their turn, provided that they have no
+    /// legal moves.
     ///
-    /// If two players pass in a row the game is considered concluded.
-    /// Do note that it is up to the caller to determine this by
-    /// calling [`.status()`].
+    /// If the current player has legal moves an error will be returned
+    /// leaving the game untouched.
     ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, GameError, Stone};
+    ///
+    /// let mut game = Game::new();
+    /// assert_eq!(game.pass(), Err(GameError::IllegalPass));
+    /// assert_eq!(game.current_turn(), Stone::Black);
+    ///
+    /// // Only White can move on this board
+    /// let board = Board::try_from((0x40, 0x80)).unwrap();
+    /// let mut game = Game::from_state(board, Stone::Black, false).unwrap();
+    /// assert!(game.pass().is_ok());
+    /// assert_eq!(game.current_turn(), Stone::White);
+    /// ```
+    pub fn pass(&mut self) -> Result<(), GameError> {
+        if !self.has_moves(self.next_player) {
+            self.pass_turn();
+            Ok(())
+        } else {
+            Err(GameError::IllegalPass)
+        }
+    }
+
+    /// The current player will pass their turn, regardless of whether or not
+    /// they have legal moves.
+    ///
+    /// This is an unchecked escape hatch for setting up positions, see
+    /// [`pass`] for a checked alternative. Passing does not affect whether the
+    /// game has concluded, which is determined by [`.status()`] from the
+    /// board alone.
+    ///
+    /// [`pass`]: crate::othello::Game::pass
     /// [`.status()`]: crate::othello::Game::status
     ///
     /// # Examples
@@ -162,24 +470,28 @@ impl Game {
 
     /// Reports the status of the game.
     ///
+    /// The game has concluded as soon as neither player has a legal move,
+    /// regardless of whether anyone passed, in which case the player with
+    /// the most stones wins.
+    ///
     /// # Examples
     /// ```rust
-    /// use magpie::othello::{Game, Status, Stone};
+    /// use magpie::othello::{Board, Game, Status, Stone};
     ///
-    /// let mut game = Game::new();
+    /// let game = Game::new();
     /// assert!(game.status() == Status::Progressing);
+    ///
+    /// // Neither player can move on a board with only black stones
+    /// let board = Board::try_from((0x18, 0)).unwrap();
+    /// let game = Game::from_state(board, Stone::White, false).unwrap();
+    /// assert_eq!(game.status(), Status::Win(Stone::Black));
     /// ```
     #[must_use]
     pub fn status(&self) -> Status {
-        let finished = self.passed_last_turn && self.board.moves_for(self.next_player).is_empty();
-        if finished {
-            let black_stones = self.board.bits_for(Stone::Black).count_set();
-            let white_stones = self.board.bits_for(Stone::White).count_set();
-
-            match black_stones.cmp(&white_stones) {
-                Ordering::Greater => Status::Win(Stone::Black),
-                Ordering::Less => Status::Win(Stone::White),
-                Ordering::Equal => Status::Draw,
+        if self.is_over() {
+            match self.score().winner() {
+                Some(stone) => Status::Win(stone),
+                None => Status::Draw,
             }
         } else {
             Status::Progressing
@@ -203,17 +515,114 @@ impl Game {
     ///     .unwrap();
     /// assert!(game.play(pos).is_ok());
     /// ```
-    pub fn play(&mut self, pos: Position) -> Result<(), GameError> {
+    pub fn play(&mut self, pos: B::Square) -> Result<(), GameError> {
         if self.is_legal_move(pos) {
             self.board.play(self.next_player, pos);
             self.next_player = self.next_player.flip();
             self.passed_last_turn = false;
+            if self.auto_pass && !self.has_moves(self.next_player) && !self.is_over() {
+                self.pass_turn();
+            }
             Ok(())
         } else {
             Err(GameError::IllegalMove)
         }
     }
 
+    /// Applies the specified action on behalf of the current player.
+    ///
+    /// Moves are checked exactly like [`play`] does. Passes always succeed,
+    /// unless the game [enforces passes], in which case they are checked
+    /// exactly like [`pass`] does.
+    ///
+    /// [`play`]: crate::othello::Game::play
+    /// [`pass`]: crate::othello::Game::pass
+    /// [enforces passes]: crate::othello::Game::with_enforced_passes
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Action, Game, Stone};
+    ///
+    /// let mut game = Game::new();
+    /// let pos = game.moves().hot_bits().next().unwrap();
+    /// game.apply(Action::Move(pos)).unwrap();
+    /// assert_eq!(game.current_turn(), Stone::White);
+    ///
+    /// game.apply(Action::Pass).unwrap();
+    /// assert_eq!(game.current_turn(), Stone::Black);
+    /// ```
+    pub fn apply(&mut self, action: Action<B::Square>) -> Result<(), GameError> {
+        match action {
+            Action::Move(pos) => self.play(pos),
+            Action::Pass if self.enforce_passes => self.pass(),
+            Action::Pass => {
+                self.pass_turn();
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns whether or not the game has concluded, which is the case when
+    /// neither player has a legal move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, Stone};
+    ///
+    /// assert!(!Game::new().is_over());
+    ///
+    /// let game = Game::from_state(Board::empty(), Stone::Black, false).unwrap();
+    /// assert!(game.is_over());
+    /// ```
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        !self.has_moves(Stone::Black) && !self.has_moves(Stone::White)
+    }
+
+    /// Returns the number of black and white stones on the board.
+    ///
+    /// Shorthand for `self.final_score(ScoringRule::Raw)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Score, Stone};
+    ///
+    /// let mut game = Game::new();
+    /// assert_eq!(game.score(), Score { black: 2, white: 2 });
+    ///
+    /// game.play("f5".try_into().unwrap()).unwrap();
+    /// assert_eq!(game.score(), Score { black: 4, white: 1 });
+    /// assert_eq!(game.score().differential(Stone::White), -3);
+    /// ```
+    #[must_use]
+    pub fn score(&self) -> Score {
+        self.final_score(ScoringRule::Raw)
+    }
+
+    /// Counts the score of the game according to the rule.
+    ///
+    /// Official tournament results award the empty squares left at the end
+    /// of a game to the winner, which [`ScoringRule::EmptiesToWinner`]
+    /// implements. The winner is the same under every rule.
+    ///
+    /// [`ScoringRule::EmptiesToWinner`]: crate::othello::ScoringRule::EmptiesToWinner
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, Score, ScoringRule, Stone};
+    ///
+    /// // The game is over after Black wiped out White's stones
+    /// let board = Board::try_from((0x1c, 0)).unwrap();
+    /// let game = Game::from_state(board, Stone::White, false).unwrap();
+    ///
+    /// let score = game.final_score(ScoringRule::EmptiesToWinner);
+    /// assert_eq!(score, Score { black: 64, white: 0 });
+    /// ```
+    #[must_use]
+    pub fn final_score(&self, rule: ScoringRule) -> Score {
+        self.board.score(rule)
+    }
+
     /// Returns a copy of the internal board used in this game.
     ///
     /// # Examples
@@ -225,7 +634,7 @@ impl Game {
     /// assert_eq!(board, Board::standard());
     /// ```
     #[must_use]
-    pub fn board(&self) -> Board {
+    pub fn board(&self) -> B {
         self.board.clone()
     }
 
@@ -258,7 +667,7 @@ impl Game {
     /// assert_eq!(game.is_legal_move(pos), true);
     /// ```
     #[must_use]
-    pub fn is_legal_move(&self, pos: Position) -> bool {
+    pub fn is_legal_move(&self, pos: B::Square) -> bool {
         self.board.is_legal_move(self.next_player, pos)
     }
 
@@ -272,7 +681,7 @@ impl Game {
     /// assert_eq!(4, game.moves().count_set());
     /// ```
     #[must_use]
-    pub fn moves(&self) -> Bitboard {
+    pub fn moves(&self) -> B::Squares {
         self.board.moves_for(self.next_player)
     }
 
@@ -291,7 +700,7 @@ impl Game {
     /// assert_eq!(black.count_set(), white.count_set());
     /// ```
     #[must_use]
-    pub fn bits_for(&self, stone: Stone) -> Bitboard {
+    pub fn bits_for(&self, stone: Stone) -> B::Squares {
         self.board.bits_for(stone)
     }
 
@@ -305,7 +714,7 @@ impl Game {
     /// assert_eq!(60, game.empty_squares().count_set());
     /// ```
     #[must_use]
-    pub fn empty_squares(&self) -> Bitboard {
+    pub fn empty_squares(&self) -> B::Squares {
         self.board.empty_squares()
     }
 
@@ -320,24 +729,12 @@ impl Game {
     /// assert_eq!(Some(Stone::White), game.stone_at(pos));
     ///  ```
     #[must_use]
-    pub fn stone_at(&self, pos: Position) -> Option<Stone> {
+    pub fn stone_at(&self, pos: B::Square) -> Option<Stone> {
         self.board.stone_at(pos)
     }
 
-    /// Returns a struct that implements [`Display`] for customizing the display of Othello boards.
-    ///
-    /// [`Display`]: std::fmt::Display
-    ///
-    /// # Examples
-    /// ```rust
-    /// use magpie::othello::{Game, Stone};
-    ///
-    /// let game = Game::new();
-    /// println!("{}", game.display());
-    ///  ```
-    #[must_use]
-    pub fn display(&'_ self) -> BoardDisplay<'_> {
-        self.board.display()
+    fn has_moves(&self, stone: Stone) -> bool {
+        self.board.moves_for(stone) != B::Squares::default()
     }
 }
 
@@ -1,10 +1,16 @@
+/// An enum that represents the actions a player can take on their turn.
+mod action;
 /// Represents a 8x8 board.
 mod bitboard;
 /// Represents an Othello board and provides convenient functions to manipulate it.
 mod board;
 /// Collection of constants useful for various calculations.
+#[allow(dead_code)]
 mod constants;
+/// The eight compass directions of a board.
+mod direction;
 /// Structs and functions that format Othello boards.
+#[cfg(feature = "std")]
 mod display;
 /// Represents an Othello game.
 mod game;
@@ -12,12 +18,31 @@ mod game;
 mod ops;
 /// Represents a single position on a 8x8 board.
 mod position;
+/// Records complete games with their metadata.
+#[cfg(feature = "std")]
+mod record;
+/// Counts the final score of a board.
+mod score;
+/// Builds games from alternative starting positions.
+mod setup;
+/// Names every square of the board.
+mod square;
 /// An enum that represents the two stone colors players can play with.
 mod stone;
 
-pub use bitboard::Bitboard;
-pub use board::{Board, BoardError};
-pub use display::{BoardDisplay, Format};
-pub use game::{Game, GameError, Status};
+pub use action::{Action, ActionTokens};
+pub use bitboard::{Bitboard, BitboardError, Bits, HotBits};
+pub use board::{Board, BoardError, DiagramError};
+pub use direction::Direction;
+#[cfg(feature = "std")]
+pub(crate) use display::write_compact;
+#[cfg(feature = "std")]
+pub use display::{BoardDisplay, Format, Glyphs, MoveDiagram};
+pub use game::{Game, GameBoard, GameError, Status};
 pub use position::{Position, PositionError};
-pub use stone::Stone;
+#[cfg(feature = "std")]
+pub use record::GameRecord;
+pub use score::{Score, ScoringRule};
+pub use setup::{Opening, Setup, SetupError};
+pub use square::Square;
+pub use stone::{Stone, StoneError};
@@ -1,5 +1,5 @@
 use crate::othello::{Bitboard, Position};
-use std::{
+use core::{
     cmp::Ordering,
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign},
 };
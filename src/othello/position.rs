@@ -1,8 +1,8 @@
 use crate::othello::{
     Bitboard,
-    constants::{FILES, POSITIONS, POSITIONS_AS_NOTATION, RANKS},
+    constants::{FILES, POSITIONS_AS_NOTATION, RANKS},
 };
-use std::{error, fmt};
+use core::{error, fmt, str::FromStr};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -93,12 +93,69 @@ use serde::{Deserialize, Serialize};
 pub struct Position(pub(crate) u64);
 
 impl Position {
+    /// All 64 positions, ordered by their index from A1 to H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let all = Position::ALL.iter().fold(Bitboard::EMPTY, |acc, pos| acc | *pos);
+    /// assert_eq!(all, Bitboard::FILLED);
+    /// assert_eq!(Position::ALL[0], Position::try_from("a1").unwrap());
+    /// ```
+    pub const ALL: [Position; 64] = {
+        let mut all = [Position(0); 64];
+        let mut index = 0;
+        while index < 64 {
+            all[index] = Position(1 << (63 - index));
+            index += 1;
+        }
+        all
+    };
+
     /// Constructs a new Position from a bitboard but does not check if
     /// a single bit is set.
-    pub(crate) fn new_unchecked(bitboard: u64) -> Self {
+    pub(crate) const fn new_unchecked(bitboard: u64) -> Self {
         Self(bitboard)
     }
 
+    /// Creates a position from a u64, or returns `None` if not exactly one
+    /// bit is set.
+    ///
+    /// Unlike the [`TryFrom`] implementation, this constructor can be used in
+    /// `const` contexts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// const A1: Option<Position> = Position::from_raw(1 << 63);
+    /// assert_eq!(A1, Position::try_from("a1").ok());
+    /// assert_eq!(Position::from_raw(0b11), None);
+    /// ```
+    #[must_use]
+    pub const fn from_raw(bitboard: u64) -> Option<Self> {
+        if bitboard.count_ones() == 1 {
+            Some(Self(bitboard))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the position to a bitboard with only the position set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let pos = Position::try_from("d4").unwrap();
+    /// assert_eq!(pos.bitboard(), Bitboard::from(pos));
+    /// ```
+    #[must_use]
+    pub const fn bitboard(self) -> Bitboard {
+        Bitboard(self.0)
+    }
+
     /// Retrieves the underlying u64.
     ///
     /// # Examples
@@ -109,10 +166,31 @@ impl Position {
     /// assert_eq!(p.raw(), (1 << 32));
     /// ```
     #[must_use]
-    pub fn raw(self) -> u64 {
+    pub const fn raw(self) -> u64 {
         self.0
     }
 
+    /// Calculates the index of the position, from 0 for A1 to 63 for H8.
+    ///
+    /// How positions are indexed can be found in the top-level
+    /// documentation for [`Position`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p = Position::try_from("b2").unwrap();
+    /// assert_eq!(p.index(), 9);
+    /// assert_eq!(Position::ALL[usize::from(p.index())], p);
+    /// ```
+    ///
+    /// [`Position`]: crate::othello::Position
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn index(self) -> u8 {
+        self.0.leading_zeros() as u8
+    }
+
     /// Calculates the zero-indexed rank the position is referring to.
     ///
     /// How ranks and files are represented can be found in the top-level
@@ -130,8 +208,8 @@ impl Position {
     ///
     /// [`Position`]: crate::othello::Position
     #[must_use]
-    pub fn rank(self) -> u8 {
-        (self.0.leading_zeros() / 8).try_into().unwrap()
+    pub const fn rank(self) -> u8 {
+        self.index() / 8
     }
 
     /// Calculates the zero-indexed file the position is referring to.
@@ -151,8 +229,8 @@ impl Position {
     ///
     /// [`Position`]: crate::othello::Position
     #[must_use]
-    pub fn file(self) -> u8 {
-        (self.0.leading_zeros() % 8).try_into().unwrap()
+    pub const fn file(self) -> u8 {
+        self.index() % 8
     }
 
     /// Calculates a human-readable board position.
@@ -170,9 +248,63 @@ impl Position {
     /// ```
     ///
     /// [`Position`]: crate::othello::Position
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn to_notation(self) -> String {
-        POSITIONS_AS_NOTATION[self.0.leading_zeros() as usize].to_string()
+        self.notation().to_string()
+    }
+
+    /// Parses a position from human-readable notation, such as `"d3"`, or
+    /// returns `None` if the notation is invalid.
+    ///
+    /// The conversion is case-insensitive and never allocates, which makes it
+    /// suitable for bulk parsing. Unlike the [`TryFrom`] implementation, it
+    /// can be used in `const` contexts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// const D3: Option<Position> = Position::from_notation("D3");
+    /// assert_eq!(D3, Position::try_from("d3").ok());
+    /// assert_eq!(Position::from_notation("i9"), None);
+    /// ```
+    #[must_use]
+    pub const fn from_notation(text: &str) -> Option<Self> {
+        match text.as_bytes() {
+            [file, rank] => Self::from_ascii(*file, *rank),
+            _ => None,
+        }
+    }
+
+    /// Parses a position from the ASCII bytes of its file and rank.
+    pub(crate) const fn from_ascii(file: u8, rank: u8) -> Option<Self> {
+        let file = file.to_ascii_lowercase().wrapping_sub(b'a');
+        let rank = rank.wrapping_sub(b'1');
+        if file < 8 && rank < 8 {
+            Some(Self(1 << (63 - (rank * 8 + file))))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the human-readable notation of the position, such as `"d3"`.
+    ///
+    /// Unlike [`to_notation`], the notation is borrowed from a static table,
+    /// so it is available without the `std` feature and in `const` contexts.
+    ///
+    /// [`to_notation`]: crate::othello::Position::to_notation
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p = Position::try_from("D3").unwrap();
+    /// assert_eq!(p.notation(), "d3");
+    /// ```
+    #[must_use]
+    pub const fn notation(self) -> &'static str {
+        POSITIONS_AS_NOTATION[self.index() as usize]
     }
 }
 
@@ -221,6 +353,7 @@ impl TryFrom<(u8, u8)> for Position {
     }
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<String> for Position {
     type Error = PositionError;
 
@@ -271,13 +404,7 @@ impl TryFrom<&str> for Position {
     ///
     /// [`Position`]: crate::othello::Position
     fn try_from(text: &str) -> Result<Self, Self::Error> {
-        let text = text.to_lowercase();
-        let bitboard = POSITIONS_AS_NOTATION
-            .iter()
-            .position(|position| position == &text)
-            .map(|index| POSITIONS[index])
-            .ok_or(PositionError::InvalidNotation)?;
-        Ok(Position::new_unchecked(bitboard))
+        Position::from_notation(text).ok_or(PositionError::InvalidNotation)
     }
 }
 
@@ -330,7 +457,53 @@ impl TryFrom<Bitboard> for Position {
     }
 }
 
+impl FromStr for Position {
+    type Err = PositionError;
+
+    /// Parses a position from human-readable notation, such as `e6`.
+    ///
+    /// The conversion is case-insensitive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p: Position = "E6".parse().unwrap();
+    /// assert_eq!(p.notation(), "e6");
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Position::try_from(text)
+    }
+}
+
+impl fmt::Display for Position {
+    /// Formats the position in lowercase notation, such as `e6`, or in
+    /// uppercase, such as `E6`, with the alternate flag (`{:#}`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p = Position::try_from("e6").unwrap();
+    /// assert_eq!(format!("{p}"), "e6");
+    /// assert_eq!(format!("{p:#}"), "E6");
+    /// assert_eq!(format!("{p:>4}"), "  e6");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let notation = self.notation();
+        if f.alternate() {
+            let mut upper = [0; 2];
+            upper.copy_from_slice(notation.as_bytes());
+            upper.make_ascii_uppercase();
+            f.pad(str::from_utf8(&upper).expect("notation is ASCII"))
+        } else {
+            f.pad(notation)
+        }
+    }
+}
+
 /// This enum represents errors that may occur when handling Positions.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum PositionError {
     /// Indicates that the bitboard did not contain exactly one set bit.
@@ -0,0 +1,165 @@
+use crate::othello::{Action, Board, Game, GameError, Score, Stone};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A complete game, with its players, date and result, and every action
+/// played from the starting position.
+///
+/// Unlike [`Game`], which only knows the current position, a record keeps the
+/// whole history of the game, which makes it suitable for storing finished
+/// games, e.g. as JSON with the `serde` feature. The metadata is free-form
+/// and never validated against the actions.
+///
+/// [`Game`]: crate::othello::Game
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Action, GameRecord};
+///
+/// let mut record = GameRecord::new().with_players("Alice", "Bob");
+/// record.push(Action::Move("f5".parse().unwrap()));
+/// record.push(Action::Move("d6".parse().unwrap()));
+///
+/// let game = record.to_game().unwrap();
+/// record.result = Some(game.score());
+/// assert_eq!(record.result.unwrap().to_string(), "3-3");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GameRecord {
+    /// The name of the black player.
+    pub black: Option<String>,
+    /// The name of the white player.
+    pub white: Option<String>,
+    /// The date the game was played, in any format.
+    pub date: Option<String>,
+    /// The final score of the game.
+    pub result: Option<Score>,
+    /// The starting position of the game.
+    pub board: Board,
+    /// The player who moved first.
+    pub first_player: Stone,
+    /// Every action played, in order, including passes.
+    pub actions: Vec<Action>,
+}
+
+impl GameRecord {
+    /// Returns an empty record of a game from the standard opening position,
+    /// with Black moving first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, GameRecord};
+    ///
+    /// let record = GameRecord::new();
+    /// assert!(record.actions.is_empty());
+    /// assert_eq!(record.to_game(), Ok(Game::new()));
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_start(&Game::new())
+    }
+
+    /// Returns an empty record of a game starting from the current position
+    /// of the game.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, GameRecord, Stone};
+    ///
+    /// let game = Game::from_state(Board::standard(), Stone::White, false).unwrap();
+    /// let record = GameRecord::from_start(&game);
+    /// assert_eq!(record.first_player, Stone::White);
+    /// ```
+    #[must_use]
+    pub fn from_start(game: &Game) -> Self {
+        Self {
+            black: None,
+            white: None,
+            date: None,
+            result: None,
+            board: game.board(),
+            first_player: game.current_turn(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the names of the black and white players.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::GameRecord;
+    ///
+    /// let record = GameRecord::new().with_players("Alice", "Bob");
+    /// assert_eq!(record.black.as_deref(), Some("Alice"));
+    /// assert_eq!(record.white.as_deref(), Some("Bob"));
+    /// ```
+    #[must_use]
+    pub fn with_players(mut self, black: &str, white: &str) -> Self {
+        self.black = Some(black.to_string());
+        self.white = Some(white.to_string());
+        self
+    }
+
+    /// Sets the date the game was played.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::GameRecord;
+    ///
+    /// let record = GameRecord::new().with_date("2024-05-01");
+    /// assert_eq!(record.date.as_deref(), Some("2024-05-01"));
+    /// ```
+    #[must_use]
+    pub fn with_date(mut self, date: &str) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    /// Appends an action to the record.
+    ///
+    /// The action is not checked, see [`to_game`] for replaying the record.
+    ///
+    /// [`to_game`]: crate::othello::GameRecord::to_game
+    pub fn push(&mut self, action: Action) {
+        self.actions.push(action);
+    }
+
+    /// Replays every action from the starting position, returning the game
+    /// in its final position.
+    ///
+    /// An error is returned if a move is illegal, or if a player passed
+    /// despite having legal moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Action, GameError, GameRecord};
+    ///
+    /// let mut record = GameRecord::new();
+    /// record.push(Action::Pass);
+    /// assert_eq!(record.to_game(), Err(GameError::IllegalPass));
+    /// ```
+    pub fn to_game(&self) -> Result<Game, GameError> {
+        let mut game = Game::from_state(self.board.clone(), self.first_player, false)?;
+        for action in &self.actions {
+            match action {
+                Action::Move(pos) => game.play(*pos)?,
+                Action::Pass => game.pass()?,
+            }
+        }
+        Ok(game)
+    }
+}
+
+impl Default for GameRecord {
+    /// Returns an empty record of a game from the standard opening position,
+    /// with Black moving first.
+    ///
+    /// Simply delegates to the [`new`] constructor.
+    ///
+    /// [`new`]: crate::othello::GameRecord::new
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,157 @@
+use crate::othello::{Board, Stone};
+use core::{cmp::Ordering, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Determines how the final score of a game is counted.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ScoringRule {
+    /// Every player scores the number of stones they own on the board.
+    #[default]
+    Raw,
+    /// Every player scores the number of stones they own on the board, with
+    /// any empty squares awarded to the winner, as in tournaments of the
+    /// World Othello Federation. Empty squares are split evenly in a draw.
+    EmptiesToWinner,
+}
+
+/// The score of both players, counted by a [`ScoringRule`].
+///
+/// [`ScoringRule`]: crate::othello::ScoringRule
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Score, ScoringRule, Stone};
+///
+/// let board = Board::standard();
+/// let score = Score::new(&board, ScoringRule::Raw);
+/// assert_eq!(score, Score { black: 2, white: 2 });
+/// assert_eq!(score.winner(), None);
+/// assert_eq!(score.to_string(), "2-2");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Score {
+    /// The score of the black player.
+    pub black: u8,
+    /// The score of the white player.
+    pub white: u8,
+}
+
+impl Score {
+    /// Counts the score of the board according to the rule.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Score, ScoringRule};
+    ///
+    /// // Black owns three stones and White one, with 60 squares left empty
+    /// let board = Board::try_from((0x07, 0x08)).unwrap();
+    ///
+    /// let raw = Score::new(&board, ScoringRule::Raw);
+    /// assert_eq!(raw, Score { black: 3, white: 1 });
+    ///
+    /// let official = Score::new(&board, ScoringRule::EmptiesToWinner);
+    /// assert_eq!(official, Score { black: 63, white: 1 });
+    /// ```
+    #[must_use]
+    pub fn new(board: &Board, rule: ScoringRule) -> Self {
+        Self::from_counts(
+            board.bits_for(Stone::Black).count_set(),
+            board.bits_for(Stone::White).count_set(),
+            board.empty_squares().count_set(),
+            rule,
+        )
+    }
+
+    /// Counts the score of a board with the specified number of stones and
+    /// empty squares according to the rule.
+    pub(crate) fn from_counts(black: u8, white: u8, empties: u8, rule: ScoringRule) -> Self {
+        let raw = Self { black, white };
+        match rule {
+            ScoringRule::Raw => raw,
+            ScoringRule::EmptiesToWinner => match raw.winner() {
+                Some(Stone::Black) => Self {
+                    black: black + empties,
+                    white,
+                },
+                Some(Stone::White) => Self {
+                    black,
+                    white: white + empties,
+                },
+                None => Self {
+                    black: black + empties / 2,
+                    white: white + empties / 2,
+                },
+            },
+        }
+    }
+
+    /// Returns the score of the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Score, Stone};
+    ///
+    /// let score = Score { black: 40, white: 24 };
+    /// assert_eq!(score.for_stone(Stone::White), 24);
+    /// ```
+    #[must_use]
+    pub fn for_stone(self, stone: Stone) -> u8 {
+        match stone {
+            Stone::Black => self.black,
+            Stone::White => self.white,
+        }
+    }
+
+    /// Returns the score of the specified player minus the score of their
+    /// opponent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Score, Stone};
+    ///
+    /// let score = Score { black: 40, white: 24 };
+    /// assert_eq!(score.differential(Stone::Black), 16);
+    /// assert_eq!(score.differential(Stone::White), -16);
+    /// ```
+    #[must_use]
+    pub fn differential(self, stone: Stone) -> i8 {
+        // Scores never exceed the 121 squares of the largest board backed by
+        // a u128, so neither the casts nor the subtraction can overflow
+        #[allow(clippy::cast_possible_wrap)]
+        let (own, other) = (
+            self.for_stone(stone) as i8,
+            self.for_stone(stone.flip()) as i8,
+        );
+        own - other
+    }
+
+    /// Returns the player with the higher score, or `None` in a draw.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Score, Stone};
+    ///
+    /// assert_eq!(Score { black: 40, white: 24 }.winner(), Some(Stone::Black));
+    /// assert_eq!(Score { black: 32, white: 32 }.winner(), None);
+    /// ```
+    #[must_use]
+    pub fn winner(self) -> Option<Stone> {
+        match self.black.cmp(&self.white) {
+            Ordering::Greater => Some(Stone::Black),
+            Ordering::Less => Some(Stone::White),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    /// Formats the score as the black score followed by the white score,
+    /// such as `33-31`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.black, self.white)
+    }
+}
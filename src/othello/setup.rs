@@ -0,0 +1,284 @@
+use crate::{
+    othello::{Board, Game, Square, Stone},
+    rng::Rng,
+};
+use core::{error, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The handicap corners, in the order they are handed out.
+const CORNERS: [Square; 4] = [Square::A1, Square::H8, Square::H1, Square::A8];
+
+// Random starts give up after this many games ended before reaching the
+// requested number of discs.
+const MAX_ATTEMPTS: usize = 1000;
+
+/// The arrangement of the four stones in the center of the board.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Opening {
+    /// The standard opening, with stones of the same color on a diagonal.
+    /// ```text
+    ///  4 |...WB...|
+    ///  5 |...BW...|
+    /// ```
+    #[default]
+    Cross,
+    /// The parallel opening, with stones of the same color side by side.
+    /// ```text
+    ///  4 |...WW...|
+    ///  5 |...BB...|
+    /// ```
+    Parallel,
+}
+
+impl Opening {
+    /// Returns a board with only the four center stones placed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Opening};
+    ///
+    /// assert_eq!(Opening::Cross.board(), Board::standard());
+    /// ```
+    #[must_use]
+    pub fn board(self) -> Board {
+        let (black, white) = match self {
+            Opening::Cross => (
+                Square::E4.bitboard() | Square::D5.bitboard(),
+                Square::D4.bitboard() | Square::E5.bitboard(),
+            ),
+            Opening::Parallel => (
+                Square::D5.bitboard() | Square::E5.bitboard(),
+                Square::D4.bitboard() | Square::E4.bitboard(),
+            ),
+        };
+        Board::try_from((black, white)).expect("center stones do not overlap")
+    }
+}
+
+/// This enum represents errors that may occur when building a game from a
+/// [`Setup`].
+///
+/// [`Setup`]: crate::othello::Setup
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SetupError {
+    /// A handicap of more than four corners was requested.
+    InvalidHandicap(u8),
+    /// The requested number of discs is lower than the number of discs
+    /// already placed, or would fill the board and end the game.
+    InvalidDiscCount(u8),
+    /// The resulting game is already over, or no game could be found that
+    /// reaches the requested number of discs.
+    Unplayable,
+}
+
+impl fmt::Display for SetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHandicap(corners) => {
+                write!(f, "invalid handicap of {corners} corners")
+            }
+            Self::InvalidDiscCount(discs) => write!(f, "invalid disc count {discs}"),
+            Self::Unplayable => write!(f, "unplayable setup"),
+        }
+    }
+}
+
+impl error::Error for SetupError {}
+
+/// Builds games that start from other positions than the standard one.
+///
+/// A setup starts from one of the center arrangements of [`Opening`], then
+/// optionally places handicap stones in the corners and finally plays
+/// random legal moves until the board holds a certain number of discs, as
+/// in the "random start" variant of the game. The resulting game is
+/// validated to be playable, meaning that at least one player can move.
+///
+/// [`Opening`]: crate::othello::Opening
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Opening, Setup, Stone};
+///
+/// let game = Setup::new()
+///     .with_opening(Opening::Parallel)
+///     .with_handicap(Stone::White, 2)
+///     .with_random_discs(12)
+///     .with_seed(42)
+///     .build()
+///     .unwrap();
+///
+/// let discs = 64 - game.empty_squares().count_set();
+/// assert_eq!(discs, 12);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Setup {
+    opening: Opening,
+    first_player: Stone,
+    handicap: Option<(Stone, u8)>,
+    random_discs: Option<u8>,
+    seed: Option<u64>,
+}
+
+impl Setup {
+    /// Returns a setup of the standard opening position with Black to move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Setup};
+    ///
+    /// assert_eq!(Setup::new().build(), Ok(Game::new()));
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            opening: Opening::Cross,
+            first_player: Stone::Black,
+            handicap: None,
+            random_discs: None,
+            seed: None,
+        }
+    }
+
+    /// Sets the arrangement of the four center stones.
+    #[must_use]
+    pub fn with_opening(mut self, opening: Opening) -> Self {
+        self.opening = opening;
+        self
+    }
+
+    /// Sets the player who moves first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Setup, Stone};
+    ///
+    /// let game = Setup::new().with_first_player(Stone::White).build().unwrap();
+    /// assert_eq!(game.current_turn(), Stone::White);
+    /// ```
+    #[must_use]
+    pub fn with_first_player(mut self, stone: Stone) -> Self {
+        self.first_player = stone;
+        self
+    }
+
+    /// Gives the specified player stones in up to four corners, which are
+    /// handed out in the order A1, H8, H1 and A8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Setup, Stone};
+    ///
+    /// let game = Setup::new().with_handicap(Stone::Black, 1).build().unwrap();
+    /// let a1 = "a1".try_into().unwrap();
+    /// assert_eq!(game.stone_at(a1), Some(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn with_handicap(mut self, stone: Stone, corners: u8) -> Self {
+        self.handicap = Some((stone, corners));
+        self
+    }
+
+    /// Plays uniformly random legal moves until the board holds the
+    /// specified number of discs.
+    ///
+    /// Games that end before reaching the number of discs are discarded and
+    /// played again.
+    #[must_use]
+    pub fn with_random_discs(mut self, discs: u8) -> Self {
+        self.random_discs = Some(discs);
+        self
+    }
+
+    /// Seeds the random moves, so that the same setup always builds the same
+    /// game. Without a seed, a source of randomness is used, or a fixed seed
+    /// when the `std` feature is disabled.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Builds the game described by the setup.
+    ///
+    /// An error is returned if the setup is invalid or the resulting game
+    /// would already be over.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Setup, SetupError, Stone};
+    ///
+    /// let setup = Setup::new().with_handicap(Stone::Black, 5);
+    /// assert_eq!(setup.build(), Err(SetupError::InvalidHandicap(5)));
+    ///
+    /// let setup = Setup::new().with_random_discs(3);
+    /// assert_eq!(setup.build(), Err(SetupError::InvalidDiscCount(3)));
+    /// ```
+    pub fn build(&self) -> Result<Game, SetupError> {
+        let mut board = self.opening.board();
+        if let Some((stone, corners)) = self.handicap {
+            if corners > 4 {
+                return Err(SetupError::InvalidHandicap(corners));
+            }
+            for corner in &CORNERS[..usize::from(corners)] {
+                board.place_stone_unchecked(stone, corner.bitboard());
+            }
+        }
+        let game = game(board, self.first_player, false);
+
+        let game = match self.random_discs {
+            None => game,
+            Some(discs) => {
+                let placed = 64 - game.empty_squares().count_set();
+                // A full board is always over, so 64 discs can never be set up
+                if discs < placed || discs >= 64 {
+                    return Err(SetupError::InvalidDiscCount(discs));
+                }
+                let mut rng = self.seed.map_or_else(Rng::unseeded, Rng::new);
+                (0..MAX_ATTEMPTS)
+                    .find_map(|_| random_game(&game, discs, &mut rng))
+                    .ok_or(SetupError::Unplayable)?
+            }
+        };
+
+        if game.is_over() {
+            Err(SetupError::Unplayable)
+        } else {
+            Ok(game)
+        }
+    }
+}
+
+impl Default for Setup {
+    /// Returns a setup of the standard opening position with Black to move.
+    ///
+    /// Simply delegates to the [`new`] constructor.
+    ///
+    /// [`new`]: crate::othello::Setup::new
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn game(board: Board, next_player: Stone, passed_last_turn: bool) -> Game {
+    Game::from_state(board, next_player, passed_last_turn).expect("setups never overlap stones")
+}
+
+/// Plays random moves until the board holds the number of discs, or returns
+/// `None` if the game ends first.
+fn random_game(start: &Game, discs: u8, rng: &mut Rng) -> Option<Game> {
+    let mut game = start.clone();
+    while 64 - game.empty_squares().count_set() < discs {
+        if game.is_over() {
+            return None;
+        }
+        match rng.choose(game.moves()) {
+            Some(pos) => game.play(pos).expect("random moves are legal"),
+            None => game.pass().expect("players without moves may pass"),
+        }
+    }
+    Some(game)
+}
@@ -0,0 +1,165 @@
+use crate::othello::{Bitboard, Position};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Names every square of the board, from `A1` to `H8`.
+///
+/// Squares offer infallible and `const` conversions to [`Position`] and
+/// [`Bitboard`], which makes fixtures and heuristics easier to read than raw
+/// bitboards or parsed notation. The discriminant of each square is its index
+/// as described in the documentation for [`Position`], starting with 0 for
+/// A1 and ending with 63 for H8.
+///
+/// [`Position`]: crate::othello::Position
+/// [`Bitboard`]: crate::othello::Bitboard
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Square, Stone};
+///
+/// let board = Board::standard();
+/// assert_eq!(board.stone_at(Square::D4.position()), Some(Stone::White));
+/// assert_eq!(Position::from(Square::E4), Position::try_from("e4").unwrap());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Square {
+    A1 = 0, B1, C1, D1, E1, F1, G1, H1,
+    A2, B2, C2, D2, E2, F2, G2, H2,
+    A3, B3, C3, D3, E3, F3, G3, H3,
+    A4, B4, C4, D4, E4, F4, G4, H4,
+    A5, B5, C5, D5, E5, F5, G5, H5,
+    A6, B6, C6, D6, E6, F6, G6, H6,
+    A7, B7, C7, D7, E7, F7, G7, H7,
+    A8, B8, C8, D8, E8, F8, G8, H8,
+}
+
+impl Square {
+    /// All 64 squares, ordered by their index from A1 to H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert_eq!(Square::ALL[0], Square::A1);
+    /// assert_eq!(Square::ALL[63], Square::H8);
+    /// ```
+    #[rustfmt::skip]
+    pub const ALL: [Square; 64] = [
+        Square::A1, Square::B1, Square::C1, Square::D1, Square::E1, Square::F1, Square::G1, Square::H1,
+        Square::A2, Square::B2, Square::C2, Square::D2, Square::E2, Square::F2, Square::G2, Square::H2,
+        Square::A3, Square::B3, Square::C3, Square::D3, Square::E3, Square::F3, Square::G3, Square::H3,
+        Square::A4, Square::B4, Square::C4, Square::D4, Square::E4, Square::F4, Square::G4, Square::H4,
+        Square::A5, Square::B5, Square::C5, Square::D5, Square::E5, Square::F5, Square::G5, Square::H5,
+        Square::A6, Square::B6, Square::C6, Square::D6, Square::E6, Square::F6, Square::G6, Square::H6,
+        Square::A7, Square::B7, Square::C7, Square::D7, Square::E7, Square::F7, Square::G7, Square::H7,
+        Square::A8, Square::B8, Square::C8, Square::D8, Square::E8, Square::F8, Square::G8, Square::H8,
+    ];
+
+    /// Returns the square with the specified index, or `None` if the index
+    /// is 64 or higher.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert_eq!(Square::from_index(9), Some(Square::B2));
+    /// assert_eq!(Square::from_index(64), None);
+    /// ```
+    #[must_use]
+    pub const fn from_index(index: u8) -> Option<Square> {
+        if index < 64 {
+            Some(Square::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the square, from 0 for A1 to 63 for H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert_eq!(Square::H1.index(), 7);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the zero-indexed rank of the square.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert_eq!(Square::C5.rank(), 4);
+    /// ```
+    #[must_use]
+    pub const fn rank(self) -> u8 {
+        self.index() / 8
+    }
+
+    /// Returns the zero-indexed file of the square.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert_eq!(Square::C5.file(), 2);
+    /// ```
+    #[must_use]
+    pub const fn file(self) -> u8 {
+        self.index() % 8
+    }
+
+    /// Converts the square to a position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Position, Square};
+    ///
+    /// const A1: Position = Square::A1.position();
+    /// assert_eq!(A1.raw(), 1 << 63);
+    /// ```
+    #[must_use]
+    pub const fn position(self) -> Position {
+        Position(1 << (63 - self.index()))
+    }
+
+    /// Converts the square to a bitboard with only the square set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Square};
+    ///
+    /// const H8: Bitboard = Square::H8.bitboard();
+    /// assert_eq!(H8.raw(), 1);
+    /// ```
+    #[must_use]
+    pub const fn bitboard(self) -> Bitboard {
+        Bitboard(1 << (63 - self.index()))
+    }
+}
+
+impl From<Square> for Position {
+    fn from(square: Square) -> Self {
+        square.position()
+    }
+}
+
+impl From<Square> for Bitboard {
+    fn from(square: Square) -> Self {
+        square.bitboard()
+    }
+}
+
+impl From<Position> for Square {
+    fn from(pos: Position) -> Self {
+        Square::ALL[usize::from(pos.index())]
+    }
+}
@@ -1,3 +1,5 @@
+use core::{error, fmt, str::FromStr};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -19,10 +21,81 @@ impl Stone {
     /// assert_eq!(Stone::White, Stone::Black.flip());
     /// ```
     #[must_use]
-    pub fn flip(self) -> Self {
+    pub const fn flip(self) -> Self {
         match self {
             Self::Black => Self::White,
             Self::White => Self::Black,
         }
     }
 }
+
+impl FromStr for Stone {
+    type Err = StoneError;
+
+    /// Parses a stone from `B` or `X` for black and `W` or `O` for white.
+    ///
+    /// The full names `black` and `white` are accepted as well. The
+    /// conversion is case-insensitive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!("b".parse(), Ok(Stone::Black));
+    /// assert_eq!("O".parse(), Ok(Stone::White));
+    /// assert_eq!("White".parse(), Ok(Stone::White));
+    /// assert!("gray".parse::<Stone>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let is = |name: &str| text.eq_ignore_ascii_case(name);
+        if is("b") || is("x") || is("black") {
+            Ok(Self::Black)
+        } else if is("w") || is("o") || is("white") {
+            Ok(Self::White)
+        } else {
+            Err(StoneError::InvalidNotation)
+        }
+    }
+}
+
+impl fmt::Display for Stone {
+    /// Formats the stone as `B` or `W`, or as `X` or `O` with the alternate
+    /// flag (`{:#}`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Stone;
+    ///
+    /// assert_eq!(format!("{}", Stone::Black), "B");
+    /// assert_eq!(format!("{:#}", Stone::White), "O");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match (self, f.alternate()) {
+            (Self::Black, false) => "B",
+            (Self::White, false) => "W",
+            (Self::Black, true) => "X",
+            (Self::White, true) => "O",
+        };
+        f.pad(text)
+    }
+}
+
+/// This enum represents errors that may occur when parsing a [`Stone`].
+///
+/// [`Stone`]: crate::othello::Stone
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StoneError {
+    /// Indicates that the text did not name a stone.
+    InvalidNotation,
+}
+
+impl fmt::Display for StoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNotation => write!(f, "invalid stone (expected B, W, X or O)"),
+        }
+    }
+}
+
+impl error::Error for StoneError {}
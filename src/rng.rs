@@ -0,0 +1,65 @@
+use crate::othello::{Bitboard, Position};
+#[cfg(feature = "std")]
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// A small and fast pseudo-random number generator (SplitMix64).
+///
+/// It is only meant for picking moves and setting up positions, never for
+/// anything security related.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Seeds the generator from the random keys the standard library uses
+    /// for hash maps.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_entropy() -> Self {
+        Self(RandomState::new().build_hasher().finish())
+    }
+
+    /// Returns a generator for callers that did not pick a seed.
+    #[cfg(feature = "std")]
+    pub(crate) fn unseeded() -> Self {
+        Self::from_entropy()
+    }
+
+    /// Returns a generator for callers that did not pick a seed.
+    ///
+    /// Without the standard library there is no source of entropy, so a
+    /// fixed seed is used instead.
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn unseeded() -> Self {
+        Self::new(0)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range `[0, n)`.
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        // Multiply-shift maps the full range onto [0, n) with negligible bias
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// Picks one of the set bits uniformly at random.
+    pub(crate) fn choose(&mut self, bitboard: Bitboard) -> Option<Position> {
+        let count = u64::from(bitboard.count_set());
+        if count == 0 {
+            return None;
+        }
+        let index = self.below(count) as usize;
+        bitboard.hot_bits().nth(index)
+    }
+}
@@ -0,0 +1,435 @@
+use crate::othello::{Bitboard, Board, GameBoard, Position, Score, ScoringRule, Stone};
+use std::cmp::Reverse;
+
+// Corners can never be flipped and are usually good to own.
+const CORNERS: u64 = 0x81_00_00_00_00_00_00_81;
+
+// Weights of the heuristic evaluation, in hundredths of a disc.
+const MOBILITY_WEIGHT: i32 = 10;
+const CORNER_WEIGHT: i32 = 100;
+const X_SQUARE_WEIGHT: i32 = 40;
+const DISC_WEIGHT: i32 = 100;
+
+/// A depth-limited alpha-beta search over Othello boards.
+///
+/// Scores are expressed in hundredths of a disc from the perspective of the
+/// player to move, so a score of `200` means that the player to move is
+/// expected to win by two discs. Finished games are scored exactly as 100
+/// times the final disc differential.
+///
+/// Once the number of empty squares drops to the configured endgame
+/// threshold the search ignores the depth limit and solves the position
+/// perfectly.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::Search;
+///
+/// let board = Board::standard();
+/// let evaluation = Search::new(3).best_move(&board, Stone::Black).unwrap();
+/// assert!(board.is_legal_move(Stone::Black, evaluation.position));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Search {
+    depth: u8,
+    endgame: u8,
+}
+
+/// The score a [`Search`] assigned to a single move.
+///
+/// [`Search`]: crate::search::Search
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Evaluation {
+    /// The move that was evaluated.
+    pub position: Position,
+    /// The score of the move, in hundredths of a disc, from the perspective
+    /// of the player making the move.
+    pub score: i32,
+    /// Whether the score is the exact, perfectly played, outcome of the game.
+    pub exact: bool,
+}
+
+impl Search {
+    /// Returns a search that looks `depth` moves ahead.
+    ///
+    /// Positions with 10 or fewer empty squares are solved perfectly. This
+    /// can be adjusted with [`with_endgame`].
+    ///
+    /// [`with_endgame`]: crate::search::Search::with_endgame
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// let search = Search::new(4);
+    /// assert_eq!(search.depth(), 4);
+    /// ```
+    #[must_use]
+    pub fn new(depth: u8) -> Self {
+        Self { depth, endgame: 10 }
+    }
+
+    /// Solves positions perfectly once at most `empties` squares are empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// let search = Search::new(4).with_endgame(12);
+    /// assert_eq!(search.endgame(), 12);
+    /// ```
+    #[must_use]
+    pub fn with_endgame(self, empties: u8) -> Self {
+        Self {
+            depth: self.depth,
+            endgame: empties,
+        }
+    }
+
+    /// Returns a search with the specified depth.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// let search = Search::new(4).with_depth(6);
+    /// assert_eq!(search.depth(), 6);
+    /// ```
+    #[must_use]
+    pub fn with_depth(self, depth: u8) -> Self {
+        Self {
+            depth,
+            endgame: self.endgame,
+        }
+    }
+
+    /// Returns the number of moves the search looks ahead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// assert_eq!(Search::new(4).depth(), 4);
+    /// ```
+    #[must_use]
+    pub fn depth(self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the number of empty squares at which the search starts
+    /// solving positions perfectly.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// assert_eq!(Search::new(4).endgame(), 10);
+    /// ```
+    #[must_use]
+    pub fn endgame(self) -> u8 {
+        self.endgame
+    }
+
+    /// Finds the best move for the specified player.
+    ///
+    /// Returns `None` if the player has no legal moves and has to pass.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::Search;
+    ///
+    /// let board = Board::standard();
+    /// let evaluation = Search::new(2).best_move(&board, Stone::Black);
+    /// assert!(evaluation.is_some());
+    /// ```
+    #[must_use]
+    pub fn best_move(self, board: &Board, stone: Stone) -> Option<Evaluation> {
+        let exact = self.is_exact(board);
+        let mut alpha = -i32::MAX;
+        let mut best: Option<Evaluation> = None;
+        for position in ordered_moves(board, stone) {
+            let mut next = board.clone();
+            next.play(stone, position);
+            let score = -self.negamax(
+                &next,
+                stone.flip(),
+                self.depth.saturating_sub(1),
+                -i32::MAX,
+                -alpha,
+            );
+            if best.is_none_or(|best| score > best.score) {
+                alpha = score;
+                best = Some(Evaluation {
+                    position,
+                    score,
+                    exact,
+                });
+            }
+        }
+        best
+    }
+
+    /// Evaluates every legal move for the specified player.
+    ///
+    /// The evaluations are sorted from best to worst. An empty vector is
+    /// returned if the player has no legal moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::Search;
+    ///
+    /// let board = Board::standard();
+    /// let evaluations = Search::new(2).evaluate_moves(&board, Stone::Black);
+    /// assert_eq!(evaluations.len(), 4);
+    /// ```
+    #[must_use]
+    pub fn evaluate_moves(self, board: &Board, stone: Stone) -> Vec<Evaluation> {
+        let exact = self.is_exact(board);
+        let mut evaluations: Vec<Evaluation> = ordered_moves(board, stone)
+            .into_iter()
+            .map(|position| {
+                let mut next = board.clone();
+                next.play(stone, position);
+                let score = -self.negamax(
+                    &next,
+                    stone.flip(),
+                    self.depth.saturating_sub(1),
+                    -i32::MAX,
+                    i32::MAX,
+                );
+                Evaluation {
+                    position,
+                    score,
+                    exact,
+                }
+            })
+            .collect();
+        evaluations.sort_by_key(|evaluation| Reverse(evaluation.score));
+        evaluations
+    }
+
+    /// Scores the board from the perspective of the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::Search;
+    ///
+    /// let board = Board::standard();
+    /// let search = Search::new(3);
+    /// let best = search.best_move(&board, Stone::Black).unwrap();
+    /// assert_eq!(search.score(&board, Stone::Black), best.score);
+    /// ```
+    #[must_use]
+    pub fn score(self, board: &Board, stone: Stone) -> i32 {
+        self.negamax(board, stone, self.depth, -i32::MAX, i32::MAX)
+    }
+
+    fn is_exact(self, board: &Board) -> bool {
+        board.empty_squares().count_set() <= self.endgame
+    }
+
+    fn negamax(self, board: &Board, stone: Stone, depth: u8, alpha: i32, beta: i32) -> i32 {
+        if self.is_exact(board) {
+            return exact(board, stone, alpha, beta, false);
+        }
+        let moves = board.moves_for(stone);
+        if moves.is_empty() {
+            if board.moves_for(stone.flip()).is_empty() {
+                return final_score(board, stone);
+            }
+            return -self.negamax(board, stone.flip(), depth, -beta, -alpha);
+        }
+        if depth == 0 {
+            return heuristic(board, stone);
+        }
+
+        let mut alpha = alpha;
+        for position in ordered(moves) {
+            let mut next = board.clone();
+            next.play(stone, position);
+            let score = -self.negamax(&next, stone.flip(), depth - 1, -beta, -alpha);
+            if score >= beta {
+                return score;
+            }
+            alpha = alpha.max(score);
+        }
+        alpha
+    }
+}
+
+impl Default for Search {
+    /// Returns a search with a depth of 6.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::Search;
+    ///
+    /// assert_eq!(Search::default().depth(), 6);
+    /// ```
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+/// Solves the board perfectly for the specified player.
+///
+/// Returns the final disc differential, from the perspective of the specified
+/// player, assuming both players play perfectly. Empty squares left at the
+/// end of the game are awarded to the winner, as in official results. This is
+/// only feasible for boards with few empty squares.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search;
+///
+/// let board = Board::try_from((u64::MAX << 1, 0)).unwrap();
+/// // Black owns every square but one and neither player can move
+/// assert_eq!(search::solve(&board, Stone::Black), 64);
+/// ```
+#[must_use]
+pub fn solve(board: &Board, stone: Stone) -> i32 {
+    exact(board, stone, -i32::MAX, i32::MAX, false) / DISC_WEIGHT
+}
+
+/// Statically evaluates the board from the perspective of the specified
+/// player without looking ahead.
+///
+/// The evaluation rewards mobility and corners and penalizes X-squares next
+/// to empty corners. The score is expressed in hundredths of a disc.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search;
+///
+/// let board = Board::standard();
+/// assert_eq!(search::heuristic(&board, Stone::Black), 0);
+/// ```
+#[must_use]
+pub fn heuristic(board: &Board, stone: Stone) -> i32 {
+    let current = board.bits_for(stone);
+    let opponent = board.bits_for(stone.flip());
+
+    let mobility = i32::from(board.moves_for(stone).count_set())
+        - i32::from(board.moves_for(stone.flip()).count_set());
+    let corners =
+        i32::from((current & CORNERS).count_set()) - i32::from((opponent & CORNERS).count_set());
+    let exposed = x_squares_next_to_empty_corners(board);
+    let x_squares =
+        i32::from((current & exposed).count_set()) - i32::from((opponent & exposed).count_set());
+
+    mobility * MOBILITY_WEIGHT + corners * CORNER_WEIGHT - x_squares * X_SQUARE_WEIGHT
+}
+
+fn x_squares_next_to_empty_corners(board: &Board) -> Bitboard {
+    let empty = board.empty_squares();
+    let mut exposed = Bitboard::EMPTY;
+    // Each corner is paired with its diagonally adjacent X-square, which
+    // often gives the corner away while the corner is still empty
+    for (corner, x_square) in [
+        (1 << 63, 1 << 54),
+        (1 << 56, 1 << 49),
+        (1 << 7, 1 << 14),
+        (1 << 0, 1 << 9),
+    ] {
+        if empty & corner != 0 {
+            exposed |= x_square;
+        }
+    }
+    exposed
+}
+
+fn final_score(board: &Board, stone: Stone) -> i32 {
+    let score = Score::new(board, ScoringRule::EmptiesToWinner);
+    i32::from(score.differential(stone)) * DISC_WEIGHT
+}
+
+fn exact(board: &Board, stone: Stone, alpha: i32, beta: i32, passed: bool) -> i32 {
+    let moves = board.moves_for(stone);
+    if moves.is_empty() {
+        if passed {
+            return final_score(board, stone);
+        }
+        return -exact(board, stone.flip(), -beta, -alpha, true);
+    }
+
+    let mut alpha = alpha;
+    let mut best = -i32::MAX;
+    for position in ordered(moves) {
+        let mut next = board.clone();
+        next.play(stone, position);
+        let score = -exact(&next, stone.flip(), -beta, -alpha, false);
+        if score >= beta {
+            return score;
+        }
+        best = best.max(score);
+        alpha = alpha.max(score);
+    }
+    best
+}
+
+fn ordered_moves(board: &Board, stone: Stone) -> Vec<Position> {
+    ordered(board.moves_for(stone)).collect()
+}
+
+// Corners are tried first since they most often cause cutoffs.
+fn ordered(moves: Bitboard) -> impl Iterator<Item = Position> {
+    (moves & CORNERS)
+        .hot_bits()
+        .chain((moves & !CORNERS).hot_bits())
+}
+
+/// Counts the number of leaf nodes reachable from the board in exactly
+/// `depth` plies.
+///
+/// This is commonly known as [perft](https://www.chessprogramming.org/Perft)
+/// and is useful for verifying move generators. A forced pass counts as a
+/// ply, while a finished game counts as a single leaf node.
+///
+/// Boards of any size can be counted, as long as they implement
+/// [`GameBoard`].
+///
+/// [`GameBoard`]: crate::othello::GameBoard
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search;
+///
+/// let board = Board::standard();
+/// assert_eq!(search::perft(&board, Stone::Black, 3), 56);
+/// ```
+#[must_use]
+pub fn perft<B: GameBoard>(board: &B, stone: Stone, depth: u8) -> u64 {
+    perft_inner(board, stone, false, depth)
+}
+
+fn perft_inner<B: GameBoard>(board: &B, stone: Stone, passed: bool, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = B::squares(board.moves_for(stone));
+    if moves.len() == 0 {
+        if passed {
+            1
+        } else {
+            perft_inner(board, stone.flip(), true, depth - 1)
+        }
+    } else if depth == 1 {
+        moves.len() as u64
+    } else {
+        moves
+            .map(|pos| {
+                let mut next = board.clone();
+                next.play(stone, pos);
+                perft_inner(&next, stone.flip(), false, depth - 1)
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,2 @@
+/// Human-readable representations of positions, stones, boards and games.
+pub mod notation;
@@ -0,0 +1,271 @@
+use crate::othello::{Bitboard, Board, Game, Position, Stone};
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Unexpected, Visitor},
+};
+use core::{fmt, marker::PhantomData};
+
+/// Types with a human-readable serde representation.
+///
+/// The representations are:
+///
+/// - [`Position`] as its notation, such as `"e6"`.
+/// - [`Stone`] as `"B"` or `"W"`.
+/// - [`Board`] as a string of 64 squares from A1 to H8, where `B` is a
+///   black stone, `W` a white stone and `.` an empty square.
+/// - [`Game`] as a struct of the `board` string and the `turn` of the player
+///   to move.
+///
+/// Deserialization is case-insensitive and also accepts `X` and `O` for
+/// stones and `-` for empty squares.
+///
+/// [`Position`]: crate::othello::Position
+/// [`Stone`]: crate::othello::Stone
+/// [`Board`]: crate::othello::Board
+/// [`Game`]: crate::othello::Game
+pub trait Notation: Sized {
+    /// Serializes the value in human-readable notation.
+    fn serialize_notation<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Deserializes the value from human-readable notation.
+    fn deserialize_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Serializes the value in human-readable notation.
+///
+/// Together with [`deserialize`], this function allows the module to be
+/// used with `#[serde(with = "magpie::serde::notation")]`.
+///
+/// [`deserialize`]: crate::serde::notation::deserialize
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Snapshot {
+///     #[serde(with = "magpie::serde::notation")]
+///     last_move: Position,
+///     #[serde(with = "magpie::serde::notation")]
+///     board: Board,
+/// }
+///
+/// let snapshot = Snapshot {
+///     last_move: "e6".parse().unwrap(),
+///     board: Board::standard(),
+/// };
+/// let json = serde_json::to_string(&snapshot).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"last_move":"e6","board":"...........................WB......BW..........................."}"#
+/// );
+/// ```
+pub fn serialize<T: Notation, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_notation(serializer)
+}
+
+/// Deserializes the value from human-readable notation.
+///
+/// Together with [`serialize`], this function allows the module to be used
+/// with `#[serde(with = "magpie::serde::notation")]`.
+///
+/// [`serialize`]: crate::serde::notation::serialize
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Game, Stone};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Match {
+///     #[serde(with = "magpie::serde::notation")]
+///     game: Game,
+/// }
+///
+/// let json = r#"{"game":{"board":"...........................WB......BW...........................","turn":"B"}}"#;
+/// let decoded: Match = serde_json::from_str(json).unwrap();
+/// assert_eq!(decoded.game.board(), Game::new().board());
+/// assert_eq!(decoded.game.current_turn(), Stone::Black);
+/// ```
+pub fn deserialize<'de, T: Notation, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    T::deserialize_notation(deserializer)
+}
+
+/// Optional values in human-readable notation.
+///
+/// Use with `#[serde(with = "magpie::serde::notation::option")]`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::Position;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Turn {
+///     #[serde(with = "magpie::serde::notation::option")]
+///     last_move: Option<Position>,
+/// }
+///
+/// let json = serde_json::to_string(&Turn { last_move: None }).unwrap();
+/// assert_eq!(json, r#"{"last_move":null}"#);
+/// let turn: Turn = serde_json::from_str(r#"{"last_move":"c4"}"#).unwrap();
+/// assert_eq!(turn.last_move, Position::from_notation("c4"));
+/// ```
+pub mod option {
+    use super::{Notation, Wrapper};
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the optional value in human-readable notation.
+    pub fn serialize<T: Notation, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&Wrapper(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes the optional value from human-readable notation.
+    pub fn deserialize<'de, T: Notation, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        let value = Option::<Wrapper<T>>::deserialize(deserializer)?;
+        Ok(value.map(|wrapper| wrapper.0))
+    }
+}
+
+// Gives any notation type a Serialize and Deserialize implementation, to
+// nest it in other serde types.
+struct Wrapper<T>(T);
+
+impl<T: Notation> Serialize for Wrapper<&T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_notation(serializer)
+    }
+}
+
+impl<'de, T: Notation> Deserialize<'de> for Wrapper<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_notation(deserializer).map(Wrapper)
+    }
+}
+
+impl Notation for Position {
+    fn serialize_notation<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.notation())
+    }
+
+    fn deserialize_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor::new("a position such as \"e6\"", |text| {
+            Position::from_notation(text)
+        }))
+    }
+}
+
+impl Notation for Stone {
+    fn serialize_notation<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            Stone::Black => "B",
+            Stone::White => "W",
+        })
+    }
+
+    fn deserialize_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor::new("a stone such as \"B\" or \"W\"", |text| {
+            text.parse().ok()
+        }))
+    }
+}
+
+impl Notation for Board {
+    fn serialize_notation<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut squares = [b'.'; 64];
+        for (square, bit) in squares.iter_mut().zip(Bitboard::FILLED.hot_bits()) {
+            match self.stone_at(bit) {
+                Some(Stone::Black) => *square = b'B',
+                Some(Stone::White) => *square = b'W',
+                None => {}
+            }
+        }
+        serializer.serialize_str(str::from_utf8(&squares).expect("squares are ASCII"))
+    }
+
+    fn deserialize_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor::new("a board of 64 squares", parse_board))
+    }
+}
+
+impl Notation for Game {
+    fn serialize_notation<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GameNotation {
+            board: self.board(),
+            turn: self.current_turn(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Only the board and the player to move are restored, so whether the
+    /// previous player passed and the passing options of the game take
+    /// their defaults.
+    fn deserialize_notation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let GameNotation { board, turn } = GameNotation::deserialize(deserializer)?;
+        Game::from_state(board, turn, false).map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Game")]
+struct GameNotation {
+    #[serde(with = "crate::serde::notation")]
+    board: Board,
+    #[serde(with = "crate::serde::notation")]
+    turn: Stone,
+}
+
+fn parse_board(text: &str) -> Option<Board> {
+    if text.len() != 64 {
+        return None;
+    }
+    let mut board = Board::empty();
+    for (square, pos) in text.bytes().zip(Bitboard::FILLED.hot_bits()) {
+        match square.to_ascii_uppercase() {
+            b'B' | b'X' => board.place_stone_unchecked(Stone::Black, pos.into()),
+            b'W' | b'O' => board.place_stone_unchecked(Stone::White, pos.into()),
+            b'.' | b'-' => {}
+            _ => return None,
+        }
+    }
+    Some(board)
+}
+
+// Deserializes a value from a string, without requiring the string to be
+// borrowed from the input or allocated.
+struct StrVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+    marker: PhantomData<T>,
+}
+
+impl<T> StrVisitor<T> {
+    fn new(expecting: &'static str, parse: fn(&str) -> Option<T>) -> Self {
+        Self {
+            expecting,
+            parse,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        (self.parse)(text).ok_or_else(|| E::invalid_value(Unexpected::Str(text), &self))
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{
+    ggf::{GgfError, GgfGame},
+    othello::{Bitboard, Board, Game, Position, Stone},
+};
+use std::fmt;
+
+/// The colors used when rendering a board as SVG.
+///
+/// Colors are written into the `fill` and `stroke` attributes of the SVG,
+/// so any SVG color such as `#2e7d32`, `rgb(0, 0, 0)` or `darkgreen` may be
+/// used. Quotes and other markup characters are escaped, so that no color
+/// can break out of its attribute.
+///
+/// # Examples
+/// ```rust
+/// use magpie::svg::Theme;
+///
+/// let theme = Theme {
+///     board: "darkgreen".to_string(),
+///     ..Theme::classic()
+/// };
+/// assert_eq!(theme.black, Theme::classic().black);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Theme {
+    /// The area around the board, where coordinates are written.
+    pub background: String,
+    /// The playing area of the board.
+    pub board: String,
+    /// The lines between squares.
+    pub grid: String,
+    /// The black stones.
+    pub black: String,
+    /// The white stones.
+    pub white: String,
+    /// The outline of every stone.
+    pub outline: String,
+    /// The markers of legal moves.
+    pub marker: String,
+    /// The coordinate labels.
+    pub text: String,
+}
+
+impl Theme {
+    /// Returns the classic theme of a green board on a white background.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::svg::Theme;
+    ///
+    /// assert_eq!(Theme::classic(), Theme::default());
+    /// ```
+    #[must_use]
+    pub fn classic() -> Self {
+        Self {
+            background: "#ffffff".to_string(),
+            board: "#2e7d32".to_string(),
+            grid: "#1b5e20".to_string(),
+            black: "#000000".to_string(),
+            white: "#ffffff".to_string(),
+            outline: "#000000".to_string(),
+            marker: "#fbc02d".to_string(),
+            text: "#000000".to_string(),
+        }
+    }
+
+    /// Returns a black and white theme suitable for printing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::svg::Theme;
+    ///
+    /// assert_eq!(Theme::monochrome().board, "#ffffff");
+    /// ```
+    #[must_use]
+    pub fn monochrome() -> Self {
+        Self {
+            background: "#ffffff".to_string(),
+            board: "#ffffff".to_string(),
+            grid: "#000000".to_string(),
+            black: "#000000".to_string(),
+            white: "#ffffff".to_string(),
+            outline: "#000000".to_string(),
+            marker: "#808080".to_string(),
+            text: "#000000".to_string(),
+        }
+    }
+
+    /// Returns the theme with every color escaped for use in an attribute.
+    fn escaped(&self) -> Self {
+        Self {
+            background: escape(&self.background),
+            board: escape(&self.board),
+            grid: escape(&self.grid),
+            black: escape(&self.black),
+            white: escape(&self.white),
+            outline: escape(&self.outline),
+            marker: escape(&self.marker),
+            text: escape(&self.text),
+        }
+    }
+}
+
+impl Default for Theme {
+    /// Returns the classic theme of a green board on a white background.
+    ///
+    /// Simply delegates to the [`classic`] constructor.
+    ///
+    /// [`classic`]: crate::svg::Theme::classic
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+/// Renders Othello boards as standalone SVG images.
+///
+/// The rendering can be customized with coordinate labels, markers on the
+/// legal moves of a player, the number of the move that placed each stone
+/// and the colors of a [`Theme`].
+///
+/// [`Theme`]: crate::svg::Theme
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::svg::{Svg, Theme};
+///
+/// let svg = Svg::new(&Board::standard())
+///     .with_moves(Stone::Black)
+///     .with_theme(Theme::monochrome())
+///     .to_string();
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.ends_with("</svg>\n"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Svg {
+    board: Board,
+    moves: Bitboard,
+    numbers: Vec<(Position, u16)>,
+    coordinates: bool,
+    square_size: u32,
+    theme: Theme,
+}
+
+impl Svg {
+    /// The largest width and height of a square in pixels, which keeps the
+    /// dimensions of the image within a `u32`.
+    pub const MAX_SQUARE_SIZE: u32 = u32::MAX / 9;
+
+    /// Returns a renderer of the board with coordinates, squares of 40
+    /// pixels and the classic theme.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use magpie::svg::Svg;
+    ///
+    /// let svg = Svg::new(&Board::standard()).to_string();
+    /// assert!(svg.contains(r#"width="360""#));
+    /// ```
+    #[must_use]
+    pub fn new(board: &Board) -> Self {
+        Self {
+            board: board.clone(),
+            moves: Bitboard::EMPTY,
+            numbers: Vec::new(),
+            coordinates: true,
+            square_size: 40,
+            theme: Theme::classic(),
+        }
+    }
+
+    /// Returns a renderer of the current position of the game, with the
+    /// legal moves of the player to move marked.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    /// use magpie::svg::Svg;
+    ///
+    /// let svg = Svg::from_game(&Game::new()).to_string();
+    /// assert_eq!(svg.matches(r#"r="4""#).count(), 4);
+    /// ```
+    #[must_use]
+    pub fn from_game(game: &Game) -> Self {
+        let mut svg = Self::new(&game.board());
+        svg.moves = game.moves();
+        svg
+    }
+
+    /// Returns a renderer of the final position of the game record, with
+    /// every stone placed by a move annotated with its move number.
+    ///
+    /// Numbers count the moves placing a stone, starting from 1, so passes
+    /// are not numbered. Stones that were flipped keep the number of the
+    /// move that placed them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    /// use magpie::svg::Svg;
+    ///
+    /// let text = "(;GM[Othello]BO[8 ---------------------------O*------*O--------------------------- *]B[F5]W[F6];)";
+    /// let record: GgfGame = text.parse().unwrap();
+    /// let svg = Svg::from_record(&record).unwrap().to_string();
+    /// assert!(svg.contains(">1</text>"));
+    /// assert!(svg.contains(">2</text>"));
+    /// ```
+    pub fn from_record(record: &GgfGame) -> Result<Self, GgfError> {
+        let game = record.to_game()?;
+        let mut svg = Self::from_game(&game);
+        let positions = record.moves().iter().filter_map(|mv| mv.position());
+        for (index, pos) in positions.enumerate() {
+            let number = u16::try_from(index + 1).expect("games have at most 60 moves");
+            svg = svg.with_move_number(pos, number);
+        }
+        Ok(svg)
+    }
+
+    /// Marks the legal moves of the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::svg::Svg;
+    ///
+    /// let svg = Svg::new(&Board::standard()).with_moves(Stone::White);
+    /// assert_eq!(svg.to_string().matches(r#"r="4""#).count(), 4);
+    /// ```
+    #[must_use]
+    pub fn with_moves(mut self, stone: Stone) -> Self {
+        self.moves = self.board.moves_for(stone);
+        self
+    }
+
+    /// Annotates the specified position with a move number.
+    ///
+    /// Annotating the same position again replaces the previous number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position};
+    /// use magpie::svg::Svg;
+    ///
+    /// let pos = Position::try_from("d4").unwrap();
+    /// let svg = Svg::new(&Board::standard()).with_move_number(pos, 7);
+    /// assert!(svg.to_string().contains(">7</text>"));
+    /// ```
+    #[must_use]
+    pub fn with_move_number(mut self, pos: Position, number: u16) -> Self {
+        self.numbers.retain(|(other, _)| *other != pos);
+        self.numbers.push((pos, number));
+        self
+    }
+
+    /// Sets whether the files A to H and ranks 1 to 8 are written around the
+    /// board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use magpie::svg::Svg;
+    ///
+    /// let svg = Svg::new(&Board::standard()).with_coordinates(false);
+    /// assert!(svg.to_string().contains(r#"width="320""#));
+    /// ```
+    #[must_use]
+    pub fn with_coordinates(mut self, coordinates: bool) -> Self {
+        self.coordinates = coordinates;
+        self
+    }
+
+    /// Sets the width and height of a single square in pixels.
+    ///
+    /// Sizes above [`MAX_SQUARE_SIZE`] are clamped to it.
+    ///
+    /// [`MAX_SQUARE_SIZE`]: crate::svg::Svg::MAX_SQUARE_SIZE
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use magpie::svg::Svg;
+    ///
+    /// let svg = Svg::new(&Board::standard())
+    ///     .with_coordinates(false)
+    ///     .with_square_size(20);
+    /// assert!(svg.to_string().contains(r#"width="160""#));
+    /// ```
+    #[must_use]
+    pub fn with_square_size(mut self, size: u32) -> Self {
+        self.square_size = size.min(Self::MAX_SQUARE_SIZE);
+        self
+    }
+
+    /// Sets the colors of the rendering.
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = self.square_size;
+        let margin = if self.coordinates { size / 2 } else { 0 };
+        let width = 8 * size + 2 * margin;
+        let theme = &self.theme.escaped();
+        // Centers of squares and labels, by zero-indexed rank or file
+        let center = |index: u32| margin + index * size + size / 2;
+        let font = |size: u32| {
+            format!(
+                r#"font-family="sans-serif" font-size="{size}" text-anchor="middle" dominant-baseline="central""#
+            )
+        };
+
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{width}" viewBox="0 0 {width} {width}">"#
+        )?;
+        writeln!(
+            f,
+            r#"<rect width="{width}" height="{width}" fill="{}"/>"#,
+            theme.background
+        )?;
+        writeln!(
+            f,
+            r#"<rect x="{margin}" y="{margin}" width="{0}" height="{0}" fill="{1}" stroke="{2}"/>"#,
+            8 * size,
+            theme.board,
+            theme.grid
+        )?;
+        for line in 1..8 {
+            let offset = margin + line * size;
+            let end = margin + 8 * size;
+            writeln!(
+                f,
+                r#"<line x1="{offset}" y1="{margin}" x2="{offset}" y2="{end}" stroke="{}"/>"#,
+                theme.grid
+            )?;
+            writeln!(
+                f,
+                r#"<line x1="{margin}" y1="{offset}" x2="{end}" y2="{offset}" stroke="{}"/>"#,
+                theme.grid
+            )?;
+        }
+
+        if self.coordinates {
+            let font = font(size * 3 / 10);
+            let far = margin + 8 * size + margin / 2;
+            let near = margin / 2;
+            for (index, file) in (0..8).zip('A'..='H') {
+                let x = center(index);
+                for y in [near, far] {
+                    writeln!(
+                        f,
+                        r#"<text x="{x}" y="{y}" {font} fill="{}">{file}</text>"#,
+                        theme.text
+                    )?;
+                }
+            }
+            for rank in 0..8 {
+                let y = center(rank);
+                for x in [near, far] {
+                    writeln!(
+                        f,
+                        r#"<text x="{x}" y="{y}" {font} fill="{}">{}</text>"#,
+                        theme.text,
+                        rank + 1
+                    )?;
+                }
+            }
+        }
+
+        for pos in (!self.board.empty_squares()).hot_bits() {
+            let (x, y) = (center(pos.file().into()), center(pos.rank().into()));
+            let fill = match self.board.stone_at(pos) {
+                Some(Stone::Black) => &theme.black,
+                _ => &theme.white,
+            };
+            writeln!(
+                f,
+                r#"<circle cx="{x}" cy="{y}" r="{}" fill="{fill}" stroke="{}"/>"#,
+                size * 2 / 5,
+                theme.outline
+            )?;
+        }
+
+        for pos in self.moves.hot_bits() {
+            let (x, y) = (center(pos.file().into()), center(pos.rank().into()));
+            writeln!(
+                f,
+                r#"<circle cx="{x}" cy="{y}" r="{}" fill="{}"/>"#,
+                size / 10,
+                theme.marker
+            )?;
+        }
+
+        let font = font(size * 2 / 5);
+        for (pos, number) in &self.numbers {
+            let (x, y) = (center(pos.file().into()), center(pos.rank().into()));
+            // Numbers are written in the color of the opposite stone to
+            // stand out, or as plain text on empty squares
+            let fill = match self.board.stone_at(*pos) {
+                Some(Stone::Black) => &theme.white,
+                Some(Stone::White) => &theme.black,
+                None => &theme.text,
+            };
+            writeln!(
+                f,
+                r#"<text x="{x}" y="{y}" {font} fill="{fill}">{number}</text>"#
+            )?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Escapes the characters that may not appear verbatim in a quoted
+/// attribute value.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl fmt::Display for Svg {
+    /// Formats the rendering as a standalone SVG document.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f)
+    }
+}
@@ -0,0 +1,612 @@
+use crate::{
+    agent::Agent,
+    ggf::{GgfGame, GgfMove},
+    othello::{Action, Game, Score, ScoringRule, Status, Stone},
+};
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+/// The z-score of a two-sided 95% confidence interval.
+const Z_95: f64 = 1.959_964;
+
+/// Plays matches between two agents.
+///
+/// A match consists of a number of rounds. In every round each opening is
+/// played twice, once with each agent playing black, so that neither agent
+/// benefits from a lopsided opening. All statistics are reported from the
+/// perspective of the first agent.
+///
+/// Agents are created through factories, one pair per worker thread, which
+/// allows games to be played in parallel by agents that are not thread-safe.
+///
+/// # Examples
+/// ```rust
+/// use magpie::agent::{GreedyAgent, RandomAgent};
+/// use magpie::tournament::Match;
+///
+/// let result = Match::new()
+///     .with_rounds(5)
+///     .run(|| GreedyAgent, || RandomAgent::with_seed(7));
+///
+/// assert_eq!(result.games().len(), 10);
+/// let tally = result.tally();
+/// assert_eq!(tally.wins + tally.draws + tally.losses, 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Match {
+    openings: Vec<Game>,
+    rounds: usize,
+    threads: usize,
+    sprt: Option<Sprt>,
+}
+
+impl Match {
+    /// Returns a match of a single round from the standard opening position,
+    /// played on a single thread.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tournament::Match;
+    ///
+    /// let tournament = Match::new();
+    /// assert_eq!(tournament.openings().len(), 1);
+    /// assert_eq!(tournament.rounds(), 1);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            openings: vec![Game::new()],
+            rounds: 1,
+            threads: 1,
+            sprt: None,
+        }
+    }
+
+    /// Replaces the positions the games start from.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    /// use magpie::tournament::Match;
+    ///
+    /// let mut opening = Game::new();
+    /// let pos = opening.moves().hot_bits().next().unwrap();
+    /// opening.play(pos).unwrap();
+    ///
+    /// let tournament = Match::new().with_openings([Game::new(), opening]);
+    /// assert_eq!(tournament.openings().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_openings<I>(mut self, openings: I) -> Self
+    where
+        I: IntoIterator<Item = Game>,
+    {
+        self.openings = openings.into_iter().collect();
+        self
+    }
+
+    /// Sets the number of times every opening is played with both colors.
+    #[must_use]
+    pub fn with_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Sets the number of games played in parallel.
+    ///
+    /// A value of zero is treated as one.
+    #[must_use]
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Stops the match early as soon as the specified sequential probability
+    /// ratio test reaches a decision.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::{GreedyAgent, RandomAgent};
+    /// use magpie::tournament::{Hypothesis, Match, Sprt};
+    ///
+    /// let result = Match::new()
+    ///     .with_rounds(500)
+    ///     .with_sprt(Sprt::new(0.0, 100.0))
+    ///     .run(|| GreedyAgent, || RandomAgent::with_seed(3));
+    ///
+    /// assert_eq!(result.decision(), Some(Hypothesis::Alternative));
+    /// assert!(result.games().len() < 1000);
+    /// ```
+    #[must_use]
+    pub fn with_sprt(mut self, sprt: Sprt) -> Self {
+        self.sprt = Some(sprt);
+        self
+    }
+
+    /// Returns the positions the games start from.
+    #[must_use]
+    pub fn openings(&self) -> &[Game] {
+        &self.openings
+    }
+
+    /// Returns the number of rounds in the match.
+    #[must_use]
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns the number of games played in parallel.
+    #[must_use]
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Returns the sequential probability ratio test used to stop the match
+    /// early, if any.
+    #[must_use]
+    pub fn sprt(&self) -> Option<Sprt> {
+        self.sprt
+    }
+
+    /// Plays the match between agents created by `first` and `second`.
+    ///
+    /// Games are returned in the order they were scheduled, which is by
+    /// round, then by opening, with the first agent playing black before
+    /// playing white. If the match is stopped early by a sequential
+    /// probability ratio test, only completed games are returned.
+    pub fn run<A, B, FA, FB>(&self, first: FA, second: FB) -> MatchResult
+    where
+        A: Agent,
+        B: Agent,
+        FA: Fn() -> A + Sync,
+        FB: Fn() -> B + Sync,
+    {
+        let total = self.rounds * self.openings.len() * 2;
+        let next = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let state = Mutex::new((Vec::with_capacity(total), Tally::default(), None));
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(total) {
+                scope.spawn(|| {
+                    let mut first = first();
+                    let mut second = second();
+                    while !stop.load(Ordering::Relaxed) {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= total {
+                            break;
+                        }
+                        let opening = (index / 2) % self.openings.len();
+                        let first_stone = if index.is_multiple_of(2) {
+                            Stone::Black
+                        } else {
+                            Stone::White
+                        };
+                        let (black, white): (&mut dyn Agent, &mut dyn Agent) = match first_stone {
+                            Stone::Black => (&mut first, &mut second),
+                            Stone::White => (&mut second, &mut first),
+                        };
+                        let mut game = play_game(&self.openings[opening], black, white);
+                        game.opening = opening;
+                        game.first = first_stone;
+
+                        let mut state = state.lock().unwrap();
+                        let (games, tally, decision) = &mut *state;
+                        if decision.is_some() {
+                            // The match was decided while this game was played
+                            break;
+                        }
+                        tally.add(&game);
+                        games.push((index, game));
+                        *decision = self.sprt.and_then(|sprt| sprt.decide(*tally));
+                        if decision.is_some() {
+                            stop.store(true, Ordering::Relaxed);
+                        }
+                    }
+                });
+            }
+        });
+
+        let (mut games, _, decision) = state.into_inner().unwrap();
+        games.sort_by_key(|(index, _)| *index);
+        MatchResult {
+            games: games.into_iter().map(|(_, game)| game).collect(),
+            decision,
+        }
+    }
+}
+
+impl Default for Match {
+    /// Returns a match of a single round from the standard opening position.
+    ///
+    /// Simply delegates to the [`new`] constructor.
+    ///
+    /// [`new`]: crate::tournament::Match::new
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Plays a single game from the opening until it is finished or forfeited.
+fn play_game(opening: &Game, black: &mut dyn Agent, white: &mut dyn Agent) -> GameResult {
+    let mut game = opening.clone().with_enforced_passes(true);
+    let mut record = GgfGame::new(game.board(), game.current_turn());
+    let mut forfeit = None;
+
+    while game.status() == Status::Progressing {
+        let stone = game.current_turn();
+        let action = match stone {
+            Stone::Black => black.play(stone, &game.board()),
+            Stone::White => white.play(stone, &game.board()),
+        };
+        if game.apply(action).is_err() {
+            forfeit = Some(stone);
+            break;
+        }
+        record.push_move(match action {
+            Action::Move(pos) => GgfMove::new(stone, pos),
+            Action::Pass => GgfMove::pass(stone),
+        });
+    }
+
+    let winner = match (forfeit, game.status()) {
+        (Some(stone), _) => Some(stone.flip()),
+        (None, Status::Win(stone)) => Some(stone),
+        (None, _) => None,
+    };
+    GameResult {
+        opening: 0,
+        first: Stone::Black,
+        record,
+        winner,
+        forfeit: forfeit.is_some(),
+        score: match forfeit {
+            // Forfeits count as a wipeout, so that the winner is never
+            // behind on discs
+            Some(Stone::Black) => Score {
+                black: 0,
+                white: 64,
+            },
+            Some(Stone::White) => Score {
+                black: 64,
+                white: 0,
+            },
+            None => game.final_score(ScoringRule::EmptiesToWinner),
+        },
+    }
+}
+
+/// The outcome of a single game played in a [`Match`].
+///
+/// [`Match`]: crate::tournament::Match
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameResult {
+    /// The index of the opening the game started from.
+    pub opening: usize,
+    /// The color played by the first agent.
+    pub first: Stone,
+    /// The moves played from the opening onwards.
+    pub record: GgfGame,
+    /// The winner of the game, or `None` if it was drawn.
+    pub winner: Option<Stone>,
+    /// Whether the game ended because the loser attempted an illegal action.
+    pub forfeit: bool,
+    /// The final score, with empty squares awarded to the winner. Forfeited
+    /// games award all 64 discs to the winner.
+    pub score: Score,
+}
+
+impl GameResult {
+    /// Returns the points scored by the first agent: 1 for a win, 0.5 for a
+    /// draw and 0 for a loss.
+    #[must_use]
+    pub fn points(&self) -> f64 {
+        match self.winner {
+            Some(stone) if stone == self.first => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        }
+    }
+
+    /// Returns the score of the first agent minus the score of the second
+    /// agent.
+    #[must_use]
+    pub fn disc_difference(&self) -> i32 {
+        i32::from(self.score.differential(self.first))
+    }
+}
+
+/// The games played in a [`Match`], along with the decision of its
+/// sequential probability ratio test.
+///
+/// [`Match`]: crate::tournament::Match
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchResult {
+    games: Vec<GameResult>,
+    decision: Option<Hypothesis>,
+}
+
+impl MatchResult {
+    /// Returns the games that were played.
+    #[must_use]
+    pub fn games(&self) -> &[GameResult] {
+        &self.games
+    }
+
+    /// Returns the hypothesis accepted by the sequential probability ratio
+    /// test, or `None` if no test was configured or it was inconclusive.
+    #[must_use]
+    pub fn decision(&self) -> Option<Hypothesis> {
+        self.decision
+    }
+
+    /// Counts the wins, draws and losses of the first agent.
+    #[must_use]
+    pub fn tally(&self) -> Tally {
+        let mut tally = Tally::default();
+        for game in &self.games {
+            tally.add(game);
+        }
+        tally
+    }
+
+    /// Returns the fraction of games won by the first agent, or `None` if no
+    /// games were played.
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        let tally = self.tally();
+        (tally.games() > 0).then(|| f64::from(tally.wins) / f64::from(tally.games()))
+    }
+
+    /// Returns the average disc differential of the first agent, or `None` if
+    /// no games were played.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::agent::GreedyAgent;
+    /// use magpie::tournament::Match;
+    ///
+    /// // Identical deterministic agents mirror each other
+    /// let result = Match::new().run(|| GreedyAgent, || GreedyAgent);
+    /// assert_eq!(result.disc_differential(), Some(0.0));
+    /// ```
+    #[must_use]
+    pub fn disc_differential(&self) -> Option<f64> {
+        let total: i32 = self.games.iter().map(GameResult::disc_difference).sum();
+        let count = u32::try_from(self.games.len()).ok()?;
+        (count > 0).then(|| f64::from(total) / f64::from(count))
+    }
+
+    /// Estimates the Elo difference between the first and second agent.
+    ///
+    /// Shorthand for `self.tally().elo()`.
+    #[must_use]
+    pub fn elo(&self) -> Option<Elo> {
+        self.tally().elo()
+    }
+}
+
+/// The number of wins, draws and losses of an agent.
+///
+/// # Examples
+/// ```rust
+/// use magpie::tournament::Tally;
+///
+/// let tally = Tally { wins: 60, draws: 10, losses: 30 };
+/// assert_eq!(tally.score(), Some(0.65));
+///
+/// let elo = tally.elo().unwrap();
+/// assert!(elo.difference > 100.0 && elo.difference < 110.0);
+/// assert!(elo.lower > 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Tally {
+    /// The number of games won.
+    pub wins: u32,
+    /// The number of games drawn.
+    pub draws: u32,
+    /// The number of games lost.
+    pub losses: u32,
+}
+
+impl Tally {
+    /// Returns the total number of games.
+    #[must_use]
+    pub fn games(self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Returns the average number of points per game, where a win is worth 1
+    /// point and a draw 0.5 points, or `None` if no games were played.
+    #[must_use]
+    pub fn score(self) -> Option<f64> {
+        let games = self.games();
+        (games > 0).then(|| (f64::from(self.wins) + f64::from(self.draws) / 2.0) / f64::from(games))
+    }
+
+    /// Estimates the Elo difference implied by the score, along with a 95%
+    /// confidence interval, or `None` if no games were played.
+    ///
+    /// The difference is infinite if one side won every game, and a bound of
+    /// the interval is infinite if it reaches a score of 0 or 1.
+    #[must_use]
+    pub fn elo(self) -> Option<Elo> {
+        let score = self.score()?;
+        let deviation = (self.variance()? / f64::from(self.games())).sqrt();
+        Some(Elo {
+            difference: elo_from_score(score),
+            lower: elo_from_score((score - Z_95 * deviation).clamp(0.0, 1.0)),
+            upper: elo_from_score((score + Z_95 * deviation).clamp(0.0, 1.0)),
+        })
+    }
+
+    /// Returns the variance of the points scored in a single game.
+    fn variance(self) -> Option<f64> {
+        let score = self.score()?;
+        let games = f64::from(self.games());
+        let wins = f64::from(self.wins) / games;
+        let draws = f64::from(self.draws) / games;
+        let losses = f64::from(self.losses) / games;
+        Some(
+            wins * (1.0 - score).powi(2)
+                + draws * (0.5 - score).powi(2)
+                + losses * (0.0 - score).powi(2),
+        )
+    }
+
+    fn add(&mut self, game: &GameResult) {
+        match game.winner {
+            Some(stone) if stone == game.first => self.wins += 1,
+            Some(_) => self.losses += 1,
+            None => self.draws += 1,
+        }
+    }
+}
+
+/// An estimated Elo difference with a 95% confidence interval.
+///
+/// The interval is not symmetric around the difference, since Elo is not
+/// linear in the score.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Elo {
+    /// The estimated Elo difference.
+    pub difference: f64,
+    /// The lower bound of the confidence interval.
+    pub lower: f64,
+    /// The upper bound of the confidence interval.
+    pub upper: f64,
+}
+
+fn elo_from_score(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+fn score_from_elo(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+/// The hypotheses of a sequential probability ratio test.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Hypothesis {
+    /// The Elo difference is at most the lower bound of the test.
+    Null,
+    /// The Elo difference is at least the upper bound of the test.
+    Alternative,
+}
+
+/// A sequential probability ratio test deciding whether the Elo difference
+/// between two agents is at most `elo0` or at least `elo1`.
+///
+/// The log-likelihood ratio is computed with the normal approximation
+/// commonly used by engine testing frameworks.
+///
+/// # Examples
+/// ```rust
+/// use magpie::tournament::{Hypothesis, Sprt, Tally};
+///
+/// let sprt = Sprt::new(0.0, 10.0);
+/// let tally = Tally { wins: 30, draws: 40, losses: 30 };
+/// assert_eq!(sprt.decide(tally), None);
+///
+/// let tally = Tally { wins: 600, draws: 100, losses: 300 };
+/// assert_eq!(sprt.decide(tally), Some(Hypothesis::Alternative));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprt {
+    elo0: f64,
+    elo1: f64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl Sprt {
+    /// Returns a test between the Elo differences `elo0` and `elo1` with
+    /// false positive and false negative rates of 5%.
+    #[must_use]
+    pub fn new(elo0: f64, elo1: f64) -> Self {
+        Self {
+            elo0,
+            elo1,
+            alpha: 0.05,
+            beta: 0.05,
+        }
+    }
+
+    /// Sets the false positive rate, i.e. the probability of accepting the
+    /// alternative hypothesis when the null hypothesis is true.
+    #[must_use]
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the false negative rate, i.e. the probability of accepting the
+    /// null hypothesis when the alternative hypothesis is true.
+    #[must_use]
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    /// Returns the lower and upper bounds of the log-likelihood ratio.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tournament::Sprt;
+    ///
+    /// let (lower, upper) = Sprt::new(0.0, 5.0).bounds();
+    /// assert!((lower + 2.944).abs() < 0.001);
+    /// assert!((upper - 2.944).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn bounds(self) -> (f64, f64) {
+        let lower = (self.beta / (1.0 - self.alpha)).ln();
+        let upper = ((1.0 - self.beta) / self.alpha).ln();
+        (lower, upper)
+    }
+
+    /// Returns the log-likelihood ratio of the alternative hypothesis over
+    /// the null hypothesis given the tally.
+    ///
+    /// The ratio is zero if no games were played. The score and its variance
+    /// are estimated with one extra win and one extra loss, so that a tally
+    /// where every game ended the same still leads to a decision.
+    #[must_use]
+    pub fn llr(self, tally: Tally) -> f64 {
+        if tally.games() == 0 {
+            return 0.0;
+        }
+        let regularized = Tally {
+            wins: tally.wins + 1,
+            draws: tally.draws,
+            losses: tally.losses + 1,
+        };
+        let (Some(score), Some(variance)) = (regularized.score(), regularized.variance()) else {
+            return 0.0;
+        };
+        let score0 = score_from_elo(self.elo0);
+        let score1 = score_from_elo(self.elo1);
+        f64::from(tally.games()) * (score1 - score0) * (2.0 * score - score0 - score1)
+            / (2.0 * variance)
+    }
+
+    /// Returns the hypothesis accepted given the tally, or `None` if more
+    /// games are needed.
+    #[must_use]
+    pub fn decide(self, tally: Tally) -> Option<Hypothesis> {
+        let llr = self.llr(tally);
+        let (lower, upper) = self.bounds();
+        if llr >= upper {
+            Some(Hypothesis::Alternative)
+        } else if llr <= lower {
+            Some(Hypothesis::Null)
+        } else {
+            None
+        }
+    }
+}
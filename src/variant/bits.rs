@@ -0,0 +1,66 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not, Shl, Shr},
+};
+
+/// An unsigned integer used as a set of squares, one bit per square.
+///
+/// This trait is implemented for `u64` and `u128`, which back boards of up to
+/// 8x8 and 11x11 squares respectively.
+pub trait Bits:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Hash
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    /// The set without any squares.
+    const ZERO: Self;
+    /// The set containing only the square with index 0.
+    const ONE: Self;
+
+    /// Returns the number of squares in the set.
+    fn count(self) -> u8;
+
+    /// Returns the index of the lowest square in the set.
+    fn lowest(self) -> u32;
+}
+
+impl Bits for u64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    // There are at most 64 bits set
+    #[allow(clippy::cast_possible_truncation)]
+    fn count(self) -> u8 {
+        self.count_ones() as u8
+    }
+
+    fn lowest(self) -> u32 {
+        self.trailing_zeros()
+    }
+}
+
+impl Bits for u128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    // There are at most 128 bits set
+    #[allow(clippy::cast_possible_truncation)]
+    fn count(self) -> u8 {
+        self.count_ones() as u8
+    }
+
+    fn lowest(self) -> u32 {
+        self.trailing_zeros()
+    }
+}
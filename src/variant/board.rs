@@ -0,0 +1,442 @@
+use crate::othello::{self, BoardError, Format, GameBoard, Score, ScoringRule, Stone};
+use crate::variant::{Bits, Size};
+use std::{error, fmt, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Column and row offsets of the eight directions stones can be flipped in.
+const DIRECTIONS: [(i8, i8); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The coordinate of a square, counted from the top-left corner.
+///
+/// Coordinates are written with a letter for the column followed by a number
+/// for the row, such as `a1` for the top-left corner or `j10` for the
+/// bottom-right corner of a 10x10 board.
+///
+/// # Examples
+/// ```rust
+/// use magpie::variant::Coord;
+///
+/// let coord: Coord = "j10".parse().unwrap();
+/// assert_eq!(coord, Coord::new(9, 9));
+/// assert_eq!(coord.to_string(), "j10");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Coord {
+    /// The column, where 0 is the leftmost column.
+    pub col: u8,
+    /// The row, where 0 is the topmost row.
+    pub row: u8,
+}
+
+impl Coord {
+    /// Returns the coordinate of the specified column and row.
+    #[must_use]
+    pub fn new(col: u8, row: u8) -> Self {
+        Self { col, row }
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Columns past Z have no letter, since no board is that wide
+        let file = if self.col < 26 {
+            char::from(b'a' + self.col)
+        } else {
+            '?'
+        };
+        write!(f, "{file}{}", u32::from(self.row) + 1)
+    }
+}
+
+/// This enum represents errors that may occur when parsing a [`Coord`].
+///
+/// [`Coord`]: crate::variant::Coord
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CoordError {
+    /// Indicates that the text is not a letter followed by a number.
+    InvalidNotation(String),
+}
+
+impl fmt::Display for CoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNotation(text) => write!(f, "invalid coordinate \"{text}\""),
+        }
+    }
+}
+
+impl error::Error for CoordError {}
+
+impl FromStr for Coord {
+    type Err = CoordError;
+
+    /// Parses a coordinate such as `c4`, ignoring case.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || CoordError::InvalidNotation(text.to_string());
+        let mut chars = text.chars();
+        let col = chars
+            .next()
+            .filter(char::is_ascii_alphabetic)
+            .ok_or_else(invalid)?;
+        let row: u8 = chars.as_str().parse().map_err(|_| invalid())?;
+        if row == 0 || !chars.as_str().starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        // The column is an ASCII letter
+        #[allow(clippy::cast_possible_truncation)]
+        let col = col.to_ascii_lowercase() as u8 - b'a';
+        Ok(Self::new(col, row - 1))
+    }
+}
+
+/// An Othello board of any [`Size`].
+///
+/// This mirrors the lower-level [`Board`] of the standard game: operations
+/// are unchecked and it is up to the caller to uphold the rules, which
+/// [`VariantGame`] does. Just like [`Board`], it implements [`GameBoard`],
+/// so that it can be played on by a [`Game`].
+///
+/// [`Size`]: crate::variant::Size
+/// [`Board`]: crate::othello::Board
+/// [`VariantGame`]: crate::variant::VariantGame
+/// [`GameBoard`]: crate::othello::GameBoard
+/// [`Game`]: crate::othello::Game
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::Stone;
+/// use magpie::variant::{Coord, Size10, VariantBoard};
+///
+/// let mut board = VariantBoard::<Size10>::standard();
+/// let coord = Coord::new(4, 3);
+/// assert!(board.is_legal_move(Stone::Black, coord));
+///
+/// board.play(Stone::Black, coord);
+/// assert_eq!(board.bits_for(Stone::Black).count_ones(), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct VariantBoard<S: Size> {
+    black_stones: S::Bits,
+    white_stones: S::Bits,
+}
+
+impl<S: Size> VariantBoard<S> {
+    /// Returns a board without any stones.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            black_stones: S::Bits::ZERO,
+            white_stones: S::Bits::ZERO,
+        }
+    }
+
+    /// Returns a board with the four center squares occupied, exactly like
+    /// the standard opening position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Stone;
+    /// use magpie::variant::{Coord, Size6, VariantBoard};
+    ///
+    /// let board = VariantBoard::<Size6>::standard();
+    /// assert_eq!(board.stone_at(Coord::new(2, 2)), Some(Stone::White));
+    /// assert_eq!(board.stone_at(Coord::new(3, 2)), Some(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn standard() -> Self {
+        let low = S::SIZE / 2 - 1;
+        let high = S::SIZE / 2;
+        let bit = |col, row| S::bit(Coord::new(col, row)).expect("center lies on the board");
+        Self {
+            black_stones: bit(high, low) | bit(low, high),
+            white_stones: bit(low, low) | bit(high, high),
+        }
+    }
+
+    /// Returns a board with the specified stones.
+    ///
+    /// An error is returned if any stones overlap or lie outside of the
+    /// board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::BoardError;
+    /// use magpie::variant::{Size6, VariantBoard};
+    ///
+    /// assert!(VariantBoard::<Size6>::from_bits(0b01, 0b10).is_ok());
+    /// assert_eq!(
+    ///     VariantBoard::<Size6>::from_bits(0b01, 0b01),
+    ///     Err(BoardError::OverlappingPieces)
+    /// );
+    /// assert_eq!(
+    ///     VariantBoard::<Size6>::from_bits(1 << 36, 0),
+    ///     Err(BoardError::OutOfBounds)
+    /// );
+    /// ```
+    pub fn from_bits(black_stones: S::Bits, white_stones: S::Bits) -> Result<Self, BoardError> {
+        if black_stones & white_stones != S::Bits::ZERO {
+            Err(BoardError::OverlappingPieces)
+        } else if (black_stones | white_stones) & !S::FULL != S::Bits::ZERO {
+            Err(BoardError::OutOfBounds)
+        } else {
+            Ok(Self {
+                black_stones,
+                white_stones,
+            })
+        }
+    }
+
+    /// Returns the set of squares occupied by the specified player.
+    #[must_use]
+    pub fn bits_for(&self, stone: Stone) -> S::Bits {
+        match stone {
+            Stone::Black => self.black_stones,
+            Stone::White => self.white_stones,
+        }
+    }
+
+    /// Returns the set of empty squares.
+    #[must_use]
+    pub fn empty_squares(&self) -> S::Bits {
+        !(self.black_stones | self.white_stones) & S::FULL
+    }
+
+    /// Returns the stone at the specified coordinate, if any.
+    #[must_use]
+    pub fn stone_at(&self, coord: Coord) -> Option<Stone> {
+        let bit = S::bit(coord)?;
+        if self.black_stones & bit != S::Bits::ZERO {
+            Some(Stone::Black)
+        } else if self.white_stones & bit != S::Bits::ZERO {
+            Some(Stone::White)
+        } else {
+            None
+        }
+    }
+
+    /// Places a stone at the specified coordinate without flipping any
+    /// stones, replacing any stone already there.
+    ///
+    /// Coordinates outside of the board are ignored.
+    pub fn place_stone_unchecked(&mut self, stone: Stone, coord: Coord) {
+        if let Some(bit) = S::bit(coord) {
+            self.remove_stone_unchecked(coord);
+            match stone {
+                Stone::Black => self.black_stones |= bit,
+                Stone::White => self.white_stones |= bit,
+            }
+        }
+    }
+
+    /// Removes any stone at the specified coordinate.
+    ///
+    /// Coordinates outside of the board are ignored.
+    pub fn remove_stone_unchecked(&mut self, coord: Coord) {
+        if let Some(bit) = S::bit(coord) {
+            self.black_stones &= !bit;
+            self.white_stones &= !bit;
+        }
+    }
+
+    /// Returns the set of legal moves for the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Stone;
+    /// use magpie::variant::{Size10, VariantBoard};
+    ///
+    /// let board = VariantBoard::<Size10>::standard();
+    /// assert_eq!(board.moves_for(Stone::Black).count_ones(), 4);
+    /// ```
+    #[must_use]
+    pub fn moves_for(&self, stone: Stone) -> S::Bits {
+        let own = self.bits_for(stone);
+        let opponent = self.bits_for(stone.flip());
+        let empty = self.empty_squares();
+
+        let mut moves = S::Bits::ZERO;
+        for direction in DIRECTIONS {
+            // Runs of opponent stones adjacent to own stones, grown one
+            // square at a time
+            let mut run = shift::<S>(own, direction) & opponent;
+            for _ in 2..S::SIZE {
+                run |= shift::<S>(run, direction) & opponent;
+            }
+            moves |= shift::<S>(run, direction) & empty;
+        }
+        moves
+    }
+
+    /// Checks if the specified player may place a stone at the coordinate.
+    #[must_use]
+    pub fn is_legal_move(&self, stone: Stone, coord: Coord) -> bool {
+        S::bit(coord).is_some_and(|bit| self.moves_for(stone) & bit != S::Bits::ZERO)
+    }
+
+    /// Places a stone at the coordinate and flips all outflanked stones.
+    ///
+    /// Just like [`Board::play`], the move is not checked for legality.
+    /// Coordinates outside of the board are ignored.
+    ///
+    /// [`Board::play`]: crate::othello::Board::play
+    pub fn play(&mut self, stone: Stone, coord: Coord) {
+        let Some(bit) = S::bit(coord) else {
+            return;
+        };
+        let own = self.bits_for(stone);
+        let opponent = self.bits_for(stone.flip());
+
+        let mut flips = S::Bits::ZERO;
+        for direction in DIRECTIONS {
+            let mut run = S::Bits::ZERO;
+            let mut square = shift::<S>(bit, direction);
+            while square & opponent != S::Bits::ZERO {
+                run |= square;
+                square = shift::<S>(square, direction);
+            }
+            if square & own != S::Bits::ZERO {
+                flips |= run;
+            }
+        }
+
+        let own = own | flips | bit;
+        let opponent = opponent & !flips;
+        match stone {
+            Stone::Black => {
+                self.black_stones = own;
+                self.white_stones = opponent;
+            }
+            Stone::White => {
+                self.white_stones = own;
+                self.black_stones = opponent;
+            }
+        }
+    }
+
+    /// Counts the score of the board according to the rule.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Score, ScoringRule};
+    /// use magpie::variant::{Size6, VariantBoard};
+    ///
+    /// let board = VariantBoard::<Size6>::standard();
+    /// assert_eq!(board.score(ScoringRule::Raw), Score { black: 2, white: 2 });
+    /// ```
+    #[must_use]
+    pub fn score(&self, rule: ScoringRule) -> Score {
+        Score::from_counts(
+            self.black_stones.count(),
+            self.white_stones.count(),
+            self.empty_squares().count(),
+            rule,
+        )
+    }
+}
+
+impl<S: Size> Default for VariantBoard<S> {
+    /// Returns a board with the standard opening position configured.
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<S: Size> GameBoard for VariantBoard<S> {
+    type Squares = S::Bits;
+    type Square = Coord;
+
+    fn standard() -> Self {
+        VariantBoard::standard()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.black_stones & self.white_stones == S::Bits::ZERO
+            && (self.black_stones | self.white_stones) & !S::FULL == S::Bits::ZERO
+    }
+
+    fn bits_for(&self, stone: Stone) -> S::Bits {
+        VariantBoard::bits_for(self, stone)
+    }
+
+    fn empty_squares(&self) -> S::Bits {
+        VariantBoard::empty_squares(self)
+    }
+
+    fn stone_at(&self, coord: Coord) -> Option<Stone> {
+        VariantBoard::stone_at(self, coord)
+    }
+
+    fn moves_for(&self, stone: Stone) -> S::Bits {
+        VariantBoard::moves_for(self, stone)
+    }
+
+    fn is_legal_move(&self, stone: Stone, coord: Coord) -> bool {
+        VariantBoard::is_legal_move(self, stone, coord)
+    }
+
+    fn play(&mut self, stone: Stone, coord: Coord) {
+        VariantBoard::play(self, stone, coord);
+    }
+
+    fn score(&self, rule: ScoringRule) -> Score {
+        VariantBoard::score(self, rule)
+    }
+
+    fn squares(squares: S::Bits) -> impl ExactSizeIterator<Item = Coord> {
+        S::coords(squares)
+    }
+}
+
+impl<S: Size> fmt::Display for VariantBoard<S> {
+    /// Formats the board like the [`Compact`] format of the standard board.
+    ///
+    /// [`Compact`]: crate::othello::Format::Compact
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyphs = Format::Compact.glyphs();
+        // Rows and columns are below the size, which fits a u8
+        #[allow(clippy::cast_possible_truncation)]
+        let coord = |row: usize, col: usize| Coord::new(col as u8, row as u8);
+        othello::write_compact(
+            f,
+            usize::from(S::SIZE),
+            true,
+            |col| char::from(b'A' + coord(0, col).col),
+            |row| row + 1,
+            |row, col| match self.stone_at(coord(row, col)) {
+                Some(Stone::Black) => glyphs.black,
+                Some(Stone::White) => glyphs.white,
+                None => glyphs.empty,
+            },
+        )
+    }
+}
+
+/// Moves every square one step in the direction, dropping squares that
+/// leave the board.
+fn shift<S: Size>(bits: S::Bits, (col, row): (i8, i8)) -> S::Bits {
+    let offset = i32::from(row) * i32::from(S::SIZE) + i32::from(col);
+    let shifted = if offset > 0 {
+        bits << offset.unsigned_abs()
+    } else {
+        bits >> offset.unsigned_abs()
+    };
+    // Squares moving off one side wrap around to the other side
+    let wrapped = match col {
+        1 => S::FIRST_COLUMN,
+        -1 => S::LAST_COLUMN,
+        _ => S::Bits::ZERO,
+    };
+    shifted & !wrapped & S::FULL
+}
@@ -0,0 +1,74 @@
+//! The [`othello`] module is tuned for the standard 8x8 board, which fits
+//! neatly into a `u64`. This module offers a slower but size-generic
+//! [`VariantBoard`], parameterized by a [`Size`]: 6x6 boards use a masked
+//! `u64` while 10x10 boards are backed by a `u128`.
+//!
+//! Both kinds of boards implement [`GameBoard`], so that the rules of the
+//! game are shared rather than duplicated. Games on variant boards are
+//! regular [`Game`]s, named [`VariantGame`] for short, and their leaf nodes
+//! are counted by [`search::perft`].
+//!
+//! Squares are identified by a [`Coord`], using the familiar notation where
+//! `a1` is the top-left corner.
+//!
+//! [`othello`]: crate::othello
+//! [`VariantBoard`]: crate::variant::VariantBoard
+//! [`Size`]: crate::variant::Size
+//! [`GameBoard`]: crate::othello::GameBoard
+//! [`Game`]: crate::othello::Game
+//! [`VariantGame`]: crate::variant::VariantGame
+//! [`search::perft`]: crate::search::perft
+//! [`Coord`]: crate::variant::Coord
+//!
+//! # Examples
+//! ```rust
+//! use magpie::variant::{Size, Size6, VariantGame};
+//! use magpie::othello::Status;
+//!
+//! let mut game = VariantGame::<Size6>::standard();
+//! while game.status() == Status::Progressing {
+//!     match Size6::coords(game.moves()).next() {
+//!         Some(coord) => game.play(coord).unwrap(),
+//!         None => game.pass().unwrap(),
+//!     }
+//! }
+//! println!("{}", game.board());
+//! ```
+
+/// Bit sets backing the boards.
+mod bits;
+/// Size-generic boards and their coordinates.
+mod board;
+/// The supported board sizes.
+mod size;
+
+pub use bits::Bits;
+pub use board::{Coord, CoordError, VariantBoard};
+pub use size::{Coords, Size, Size6, Size10};
+
+use crate::othello::Game;
+
+/// An Othello game on a board of any [`Size`].
+///
+/// This is the same [`Game`] that is played on the standard board, so it
+/// enforces legal moves and passes and supports automatic passing in
+/// exactly the same way. Since [`Game::new`] is reserved for the standard
+/// board, games start from [`Game::standard`] instead.
+///
+/// [`Size`]: crate::variant::Size
+/// [`Game`]: crate::othello::Game
+/// [`Game::new`]: crate::othello::Game::new
+/// [`Game::standard`]: crate::othello::Game::standard
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Action, GameError, Stone};
+/// use magpie::variant::{Size10, VariantGame};
+///
+/// let mut game = VariantGame::<Size10>::standard().with_enforced_passes(true);
+/// assert_eq!(game.apply(Action::Pass), Err(GameError::IllegalPass));
+///
+/// game.play("d5".parse().unwrap()).unwrap();
+/// assert_eq!(game.current_turn(), Stone::White);
+/// ```
+pub type VariantGame<S> = Game<VariantBoard<S>>;
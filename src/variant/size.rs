@@ -0,0 +1,139 @@
+use crate::variant::{Bits, Coord};
+use std::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+/// The size of a square board.
+///
+/// Squares are numbered row by row, so that `a1` is bit 0, `b1` is bit 1 and
+/// so on.
+///
+/// # Examples
+/// ```rust
+/// use magpie::variant::{Coord, Size, Size10};
+///
+/// assert_eq!(Size10::SIZE, 10);
+/// let coord = Coord::new(9, 9);
+/// assert_eq!(Size10::bit(coord), Some(1 << 99));
+/// ```
+pub trait Size: Copy + Debug + Default + Eq + Hash {
+    /// The bit set backing boards of this size.
+    type Bits: Bits;
+
+    /// The number of rows and columns.
+    const SIZE: u8;
+    /// Every square on the board.
+    const FULL: Self::Bits;
+    /// Every square in the leftmost column.
+    const FIRST_COLUMN: Self::Bits;
+    /// Every square in the rightmost column.
+    const LAST_COLUMN: Self::Bits;
+
+    /// Returns the bit of the specified coordinate, or `None` if it lies
+    /// outside of the board.
+    #[must_use]
+    fn bit(coord: Coord) -> Option<Self::Bits> {
+        (coord.col < Self::SIZE && coord.row < Self::SIZE)
+            .then(|| Self::Bits::ONE << u32::from(coord.row * Self::SIZE + coord.col))
+    }
+
+    /// Returns an iterator over the coordinates of all squares in the set,
+    /// row by row.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::variant::{Coord, Size, Size6};
+    ///
+    /// let coords: Vec<Coord> = Size6::coords(0b11).collect();
+    /// assert_eq!(coords, vec![Coord::new(0, 0), Coord::new(1, 0)]);
+    /// ```
+    #[must_use]
+    fn coords(bits: Self::Bits) -> Coords<Self> {
+        Coords {
+            bits,
+            size: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the coordinates of a set of squares.
+///
+/// Returned by [`Size::coords`].
+///
+/// [`Size::coords`]: crate::variant::Size::coords
+#[derive(Clone, Debug)]
+pub struct Coords<S: Size> {
+    bits: S::Bits,
+    size: PhantomData<S>,
+}
+
+impl<S: Size> Iterator for Coords<S> {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == S::Bits::ZERO {
+            return None;
+        }
+        let index = self.bits.lowest();
+        self.bits &= !(S::Bits::ONE << index);
+        // The index is below 128, and so is the size
+        #[allow(clippy::cast_possible_truncation)]
+        let (col, row) = (index % u32::from(S::SIZE), index / u32::from(S::SIZE));
+        Some(Coord::new(col as u8, row as u8))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = usize::from(self.bits.count());
+        (count, Some(count))
+    }
+}
+
+impl<S: Size> ExactSizeIterator for Coords<S> {}
+
+const fn full(size: u32) -> u128 {
+    if size * size == 128 {
+        u128::MAX
+    } else {
+        (1 << (size * size)) - 1
+    }
+}
+
+const fn column(size: u32, col: u32) -> u128 {
+    let mut mask = 0;
+    let mut row = 0;
+    while row < size {
+        mask |= 1 << (row * size + col);
+        row += 1;
+    }
+    mask
+}
+
+macro_rules! size {
+    ($(#[$m:meta])* $name:ident, $bits:ty, $size:literal) => {
+        $(#[$m])*
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+        pub struct $name;
+
+        // The masks are computed as u128 and always fit the backing type
+        #[allow(clippy::cast_possible_truncation)]
+        impl Size for $name {
+            type Bits = $bits;
+
+            const SIZE: u8 = $size;
+            const FULL: $bits = full($size) as $bits;
+            const FIRST_COLUMN: $bits = column($size, 0) as $bits;
+            const LAST_COLUMN: $bits = column($size, $size - 1) as $bits;
+        }
+    };
+}
+
+size!(
+    /// A 6x6 board, backed by a `u64`.
+    Size6,
+    u64,
+    6
+);
+size!(
+    /// A 10x10 board, backed by a `u128`.
+    Size10,
+    u128,
+    10
+);
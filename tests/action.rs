@@ -0,0 +1,85 @@
+mod common;
+
+use common::ShadowPosition;
+use magpie::othello::{Action, Position, PositionError};
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn tokens_roundtrip(positions: Vec<(ShadowPosition, bool)>) {
+    let actions: Vec<Action> = positions
+        .into_iter()
+        .map(|(pos, pass)| {
+            if pass {
+                Action::Pass
+            } else {
+                Action::Move(Position::try_from(pos).unwrap())
+            }
+        })
+        .collect();
+
+    let lower: String = actions.iter().map(ToString::to_string).collect();
+    let upper: String = actions.iter().map(|action| format!("{action:#}")).collect();
+    let spaced = actions
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    for text in [lower, upper, spaced] {
+        let parsed: Result<Vec<Action>, _> = Action::tokens(&text).collect();
+        assert_eq!(parsed, Ok(actions.clone()));
+    }
+}
+
+#[quickcheck]
+fn from_str_matches_display(position: ShadowPosition, pass: bool) {
+    let action = if pass {
+        Action::Pass
+    } else {
+        Action::Move(Position::try_from(position).unwrap())
+    };
+    assert_eq!(action.to_string().parse(), Ok(action));
+    assert_eq!(format!("{action:#}").parse(), Ok(action));
+}
+
+#[test]
+fn pass_tokens() {
+    for text in ["pass", "PASS", "Pass", "pa", "PA", "--"] {
+        assert_eq!(text.parse(), Ok(Action::Pass));
+        let actions: Vec<_> = Action::tokens(text).collect();
+        assert_eq!(actions, vec![Ok(Action::Pass)]);
+    }
+}
+
+#[test]
+fn tokens_stop_at_invalid_token() {
+    let mut tokens = Action::tokens("f5 d6 i9 c3");
+    assert!(matches!(tokens.next(), Some(Ok(Action::Move(_)))));
+    assert!(matches!(tokens.next(), Some(Ok(Action::Move(_)))));
+    assert_eq!(tokens.next(), Some(Err(PositionError::InvalidNotation)));
+    assert_eq!(tokens.remainder(), "i9 c3");
+    assert_eq!(tokens.next(), None);
+    assert_eq!(tokens.remainder(), "i9 c3");
+}
+
+#[test]
+fn tokens_reject_trailing_character() {
+    let mut tokens = Action::tokens("f5d");
+    assert!(tokens.next().unwrap().is_ok());
+    assert_eq!(tokens.next(), Some(Err(PositionError::InvalidNotation)));
+    assert_eq!(tokens.remainder(), "d");
+}
+
+#[test]
+fn tokens_handle_multibyte_characters() {
+    let mut tokens = Action::tokens("f5ü6");
+    assert!(tokens.next().unwrap().is_ok());
+    assert_eq!(tokens.next(), Some(Err(PositionError::InvalidNotation)));
+    assert_eq!(tokens.remainder(), "ü6");
+}
+
+#[test]
+fn empty_text_has_no_tokens() {
+    assert_eq!(Action::tokens("").next(), None);
+    assert_eq!(Action::tokens(" \n\t").next(), None);
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::{ShadowBoard, ShadowStone};
+use magpie::agent::{
+    Action, Agent, GreedyAgent, HumanAgent, MinimaxAgent, MobilityAgent, RandomAgent,
+};
+use magpie::othello::{Board, Game, Position, Status, Stone};
+use quickcheck_macros::quickcheck;
+
+fn agents(seed: u64) -> Vec<Box<dyn Agent>> {
+    vec![
+        Box::new(RandomAgent::with_seed(seed)),
+        Box::new(GreedyAgent),
+        Box::new(MobilityAgent),
+        Box::new(MinimaxAgent::new(1)),
+    ]
+}
+
+fn is_legal(board: &Board, stone: Stone, action: Action) -> bool {
+    match action {
+        Action::Move(pos) => board.is_legal_move(stone, pos),
+        Action::Pass => board.moves_for(stone).is_empty(),
+    }
+}
+
+#[quickcheck]
+fn agents_play_legal_actions(board: ShadowBoard, stone: ShadowStone, seed: u64) -> bool {
+    let board = Board::try_from(board).unwrap();
+    let stone = Stone::from(stone);
+    agents(seed)
+        .iter_mut()
+        .all(|agent| is_legal(&board, stone, agent.play(stone, &board)))
+}
+
+#[test]
+fn agents_pass_without_moves() {
+    for mut agent in agents(0) {
+        assert_eq!(agent.play(Stone::Black, &Board::empty()), Action::Pass);
+    }
+}
+
+#[test]
+fn agents_finish_games() {
+    for (mut black, mut white) in agents(1).into_iter().zip(agents(2).into_iter().rev()) {
+        let mut game = Game::new();
+        while game.status() == Status::Progressing {
+            let stone = game.current_turn();
+            let agent = match stone {
+                Stone::Black => &mut black,
+                Stone::White => &mut white,
+            };
+            game.apply(agent.play(stone, &game.board())).unwrap();
+        }
+    }
+}
+
+#[test]
+fn greedy_agent_maximizes_flips() {
+    // Black on A1 can flip one stone by playing A3 or three stones by playing
+    // E1
+    let black = 0x80_00_00_00_00_00_00_00;
+    let white = 0x70_80_00_00_00_00_00_00;
+    let board = Board::try_from((black, white)).unwrap();
+    let e1 = Position::try_from("e1").unwrap();
+    assert_eq!(GreedyAgent.play(Stone::Black, &board), Action::Move(e1));
+}
+
+#[test]
+fn greedy_agent_breaks_ties_towards_a1() {
+    // Every opening move flips a single stone
+    let d3 = Position::try_from("d3").unwrap();
+    let action = GreedyAgent.play(Stone::Black, &Board::standard());
+    assert_eq!(action, Action::Move(d3));
+}
+
+#[test]
+fn mobility_agent_breaks_ties_towards_a1() {
+    // The opening moves are symmetrical, so they leave the same mobility
+    let d3 = Position::try_from("d3").unwrap();
+    let action = MobilityAgent.play(Stone::Black, &Board::standard());
+    assert_eq!(action, Action::Move(d3));
+}
+
+#[quickcheck]
+fn seeded_random_agent_is_deterministic(board: ShadowBoard, seed: u64) -> bool {
+    let board = Board::try_from(board).unwrap();
+    let mut first = RandomAgent::with_seed(seed);
+    let mut second = RandomAgent::with_seed(seed);
+    (0..8).all(|_| first.play(Stone::Black, &board) == second.play(Stone::Black, &board))
+}
+
+#[test]
+fn human_agent_rejects_illegal_input() {
+    let input = "hello\na1\npass\nf5\n".as_bytes();
+    let mut agent = HumanAgent::new(input, Vec::new());
+    let action = agent.play(Stone::Black, &Board::standard());
+    assert_eq!(action, Action::Move(Position::try_from("f5").unwrap()));
+
+    let (_, output) = agent.into_inner();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches("> ").count(), 4);
+}
+
+#[test]
+fn human_agent_passes_without_moves() {
+    let input = "d3\npass\n".as_bytes();
+    let mut agent = HumanAgent::new(input, Vec::new());
+    assert_eq!(agent.play(Stone::Black, &Board::empty()), Action::Pass);
+}
+
+#[test]
+#[should_panic(expected = "Failed to read action")]
+fn human_agent_panics_on_closed_input() {
+    let mut agent = HumanAgent::new("".as_bytes(), Vec::new());
+    agent.play(Stone::Black, &Board::standard());
+}
@@ -0,0 +1,49 @@
+use magpie::{
+    arbitrary::playout,
+    othello::{Bitboard, Board, Game, Position, Stone},
+};
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn reachable_boards_keep_the_center(board: Board) {
+    assert!(board.is_valid());
+    assert_eq!(board.empty_squares() & Bitboard::CENTER, 0);
+}
+
+#[quickcheck]
+fn reachable_games_can_continue(game: Game) {
+    // A player without moves passes, after which the opponent can move
+    if !game.is_over() && game.moves().is_empty() {
+        let mut game = game;
+        game.pass().unwrap();
+        assert!(!game.moves().is_empty());
+    }
+}
+
+#[quickcheck]
+fn playout_places_one_stone_per_move(seed: u64, moves: u8) {
+    let moves = moves % 61;
+    let game = playout(seed, moves);
+    let stones = 64 - game.empty_squares().count_set();
+    if game.is_over() {
+        assert!(stones <= moves + 4);
+    } else {
+        assert_eq!(stones, moves + 4);
+    }
+    assert_eq!(game, playout(seed, moves));
+}
+
+#[test]
+fn playout_without_moves_is_the_standard_opening() {
+    assert_eq!(playout(7, 0), Game::new());
+}
+
+#[quickcheck]
+fn positions_have_a_single_bit(pos: Position) {
+    assert_eq!(pos.raw().count_ones(), 1);
+}
+
+#[quickcheck]
+fn stones_flip(stone: Stone) {
+    assert_ne!(stone, stone.flip());
+}
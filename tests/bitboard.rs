@@ -1,7 +1,7 @@
 mod common;
 
 use common::{ShadowBitboard, ShadowPosition};
-use magpie::othello::{Bitboard, Position};
+use magpie::othello::{Bitboard, BitboardError, Position};
 use quickcheck_macros::quickcheck;
 
 #[quickcheck]
@@ -90,3 +90,101 @@ fn stones_bit_count(rand_bitboard: ShadowBitboard) {
 
     assert_eq!(expected as usize, result);
 }
+
+#[quickcheck]
+fn lsb_first_is_msb_first_reversed(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let mut msb: Vec<Position> = bitboard.hot_bits_msb_first().collect();
+    let lsb: Vec<Position> = bitboard.hot_bits_lsb_first().collect();
+    msb.reverse();
+    assert_eq!(msb, lsb);
+}
+
+#[quickcheck]
+fn hot_bits_from_both_ends(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let expected: Vec<Position> = bitboard.hot_bits().collect();
+    let mut iter = bitboard.hot_bits();
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    loop {
+        assert_eq!(iter.len(), expected.len() - front.len() - back.len());
+        let Some(pos) = iter.next() else { break };
+        front.push(pos);
+        let Some(pos) = iter.next_back() else { break };
+        back.push(pos);
+    }
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    back.reverse();
+    front.extend(back);
+    assert_eq!(front, expected);
+}
+
+#[quickcheck]
+fn pop_matches_hot_bits(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let mut remaining = bitboard;
+    let popped: Vec<Position> = std::iter::from_fn(|| remaining.pop_first()).collect();
+    assert!(remaining.is_empty());
+    assert_eq!(popped, bitboard.hot_bits().collect::<Vec<_>>());
+
+    let mut remaining = bitboard;
+    let popped: Vec<Position> = std::iter::from_fn(|| remaining.pop_last()).collect();
+    assert!(remaining.is_empty());
+    assert_eq!(popped, bitboard.hot_bits().rev().collect::<Vec<_>>());
+}
+
+#[quickcheck]
+fn bits_from_both_ends(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let forward: Vec<Bitboard> = bitboard.bits().collect();
+    let mut backward: Vec<Bitboard> = bitboard.bits().rev().collect();
+    backward.reverse();
+    assert_eq!(forward.len(), 64);
+    assert_eq!(forward, backward);
+    let combined = forward.iter().fold(Bitboard::EMPTY, |acc, b| acc | *b);
+    assert_eq!(combined, bitboard);
+    for (index, bits) in forward.iter().enumerate() {
+        assert!(bits.count_set() <= 1);
+        assert_eq!(*bits, bitboard & (1 << (63 - index)));
+    }
+}
+
+#[quickcheck]
+fn display_roundtrip(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    assert_eq!(bitboard.to_string().parse(), Ok(bitboard));
+    assert_eq!(format!("{bitboard:#}").parse(), Ok(bitboard));
+    assert_eq!(format!("{bitboard:#}"), format!("{:#018x}", bitboard.raw()));
+}
+
+#[test]
+fn display_grid_layout() {
+    let grid = Bitboard::from(0x8000_0000_0000_0001).to_string();
+    let rows: Vec<&str> = grid.lines().collect();
+    assert_eq!(rows.len(), 8);
+    assert_eq!(rows[0], "1.......");
+    assert_eq!(rows[7], ".......1");
+}
+
+#[test]
+fn parse_rejects_invalid_bitboards() {
+    let too_short = ".".repeat(63);
+    let too_long = ".".repeat(65);
+    for text in [
+        "",
+        "0x",
+        "0x+1",
+        "0x10000000000000000",
+        "0xg",
+        "12",
+        &too_short,
+        &too_long,
+    ] {
+        assert_eq!(
+            text.parse::<Bitboard>(),
+            Err(BitboardError::InvalidNotation)
+        );
+    }
+}
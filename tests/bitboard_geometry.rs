@@ -0,0 +1,137 @@
+mod common;
+
+use common::{ShadowBitboard, ShadowPosition};
+use magpie::othello::{Bitboard, Direction, Position};
+use quickcheck_macros::quickcheck;
+
+/// The change in rank and file of a single step in each direction.
+fn step(direction: Direction) -> (i8, i8) {
+    match direction {
+        Direction::North => (-1, 0),
+        Direction::NorthEast => (-1, 1),
+        Direction::East => (0, 1),
+        Direction::SouthEast => (1, 1),
+        Direction::South => (1, 0),
+        Direction::SouthWest => (1, -1),
+        Direction::West => (0, -1),
+        Direction::NorthWest => (-1, -1),
+    }
+}
+
+/// Walks from the position in the direction, yielding every square passed.
+fn walk(pos: Position, direction: Direction) -> impl Iterator<Item = Position> {
+    let (rank_step, file_step) = step(direction);
+    (1..8).map_while(move |distance| {
+        let rank = i8::try_from(pos.rank()).unwrap() + rank_step * distance;
+        let file = i8::try_from(pos.file()).unwrap() + file_step * distance;
+        let rank = u8::try_from(rank).ok()?;
+        let file = u8::try_from(file).ok()?;
+        Position::try_from((rank, file)).ok()
+    })
+}
+
+fn shadow_shift(bitboard: Bitboard, direction: Direction) -> Bitboard {
+    bitboard
+        .hot_bits()
+        .filter_map(|pos| walk(pos, direction).next())
+        .fold(Bitboard::EMPTY, |acc, pos| acc | pos)
+}
+
+#[quickcheck]
+fn shift_matches_steps(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    for direction in Direction::ALL {
+        assert_eq!(bitboard.shift(direction), shadow_shift(bitboard, direction));
+    }
+}
+
+#[quickcheck]
+fn named_shifts(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let named = [
+        bitboard.north(),
+        bitboard.north_east(),
+        bitboard.east(),
+        bitboard.south_east(),
+        bitboard.south(),
+        bitboard.south_west(),
+        bitboard.west(),
+        bitboard.north_west(),
+    ];
+    for (direction, shifted) in Direction::ALL.into_iter().zip(named) {
+        assert_eq!(bitboard.shift(direction), shifted);
+    }
+}
+
+#[quickcheck]
+fn opposite_is_involution(pos: ShadowPosition) {
+    let pos = Position::try_from(pos).unwrap();
+    for direction in Direction::ALL {
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_ne!(direction.opposite(), direction);
+        let back = Bitboard::from(pos)
+            .shift(direction)
+            .shift(direction.opposite());
+        assert!(back.is_empty() || back == Bitboard::from(pos));
+    }
+}
+
+#[quickcheck]
+fn dilate_contains_neighbors(bitboard: ShadowBitboard) {
+    let bitboard = Bitboard::from(bitboard);
+    let expected = Direction::ALL.into_iter().fold(bitboard, |acc, direction| {
+        acc | shadow_shift(bitboard, direction)
+    });
+    assert_eq!(bitboard.dilate(), expected);
+    assert_eq!(bitboard.neighbors(), expected & !bitboard);
+}
+
+#[quickcheck]
+fn ray_matches_walk(pos: ShadowPosition) {
+    let pos = Position::try_from(pos).unwrap();
+    for direction in Direction::ALL {
+        let expected = walk(pos, direction).fold(Bitboard::EMPTY, |acc, pos| acc | pos);
+        assert_eq!(Bitboard::ray(pos, direction), expected);
+    }
+}
+
+#[quickcheck]
+fn between_is_symmetric(a: ShadowPosition, b: ShadowPosition) {
+    let (a, b) = (
+        Position::try_from(a).unwrap(),
+        Position::try_from(b).unwrap(),
+    );
+    let between = Bitboard::between(a, b);
+    assert_eq!(between, Bitboard::between(b, a));
+    assert!((between & a).is_empty() && (between & b).is_empty());
+
+    let aligned = Direction::ALL
+        .into_iter()
+        .find(|direction| walk(a, *direction).any(|pos| pos == b));
+    match aligned {
+        Some(direction) => {
+            let expected = walk(a, direction)
+                .take_while(|pos| *pos != b)
+                .fold(Bitboard::EMPTY, |acc, pos| acc | pos);
+            assert_eq!(between, expected);
+        }
+        None => assert!(between.is_empty()),
+    }
+}
+
+#[test]
+fn flood_fill_follows_connections() {
+    let pos = |notation: &str| Bitboard::from(Position::try_from(notation).unwrap());
+    // Two separate regions, where C3 only touches B2 diagonally
+    let region = pos("a1") | pos("a2") | pos("b2") | pos("c3") | pos("d4");
+    let other = pos("h8") | pos("g8");
+    let mask = region | other;
+
+    assert_eq!(pos("a1").flood_fill(mask), region);
+    assert_eq!(pos("h8").flood_fill(mask), other);
+    assert_eq!(pos("e5").flood_fill(mask), Bitboard::EMPTY);
+    assert_eq!(
+        Bitboard::FILLED.flood_fill(Bitboard::FILLED),
+        Bitboard::FILLED
+    );
+}
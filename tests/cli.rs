@@ -0,0 +1,124 @@
+use std::process::{Command, Output};
+
+fn magpie(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_magpie"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = magpie(args);
+    assert!(output.status.success(), "magpie {args:?} failed");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn convert_transcript_roundtrip() {
+    let ggf = stdout(&["convert", "f5d6c3d3", "--to", "ggf"]);
+    assert!(ggf.starts_with("(;GM[Othello]"));
+    assert!(ggf.contains("B[F5]W[D6]B[C3]W[D3]"));
+
+    let transcript = stdout(&["convert", ggf.trim(), "--to", "transcript"]);
+    assert_eq!(transcript.trim(), "f5d6c3d3");
+}
+
+#[test]
+fn convert_to_board() {
+    let board = stdout(&["convert", "f5", "--to", "board"]);
+    let expected = format!(
+        "{}OX{}XXX{} O",
+        "-".repeat(27),
+        "-".repeat(6),
+        "-".repeat(26)
+    );
+    assert_eq!(board.trim(), expected);
+}
+
+#[test]
+fn convert_board_to_transcript_fails() {
+    let board = format!(
+        "{}OX{}XO{} X",
+        "-".repeat(27),
+        "-".repeat(6),
+        "-".repeat(27)
+    );
+    let output = magpie(&["convert", &board, "--to", "transcript"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn show_board() {
+    let output = stdout(&["show", "f5", "--compact", "--moves"]);
+    assert!(output.contains("5 |...BBB..|"));
+    assert!(output.contains("Black 4 - 1 White, White to move"));
+}
+
+#[test]
+fn perft_counts_nodes() {
+    let output = stdout(&["perft", "4"]);
+    let counts: Vec<&str> = output
+        .lines()
+        .map(|line| line.split_whitespace().nth(2).unwrap())
+        .collect();
+    assert_eq!(counts, vec!["4", "12", "56", "244"]);
+}
+
+#[test]
+fn solve_position() {
+    // Black owns everything but the last rank, where playing H8 flips all of
+    // White's stones
+    let board = format!("{}X{}- X", "X".repeat(56), "O".repeat(6));
+    let output = stdout(&["solve", &board]);
+    assert!(output.contains("Black plays h8"), "{output}");
+    assert!(output.contains("+64"), "{output}");
+}
+
+#[test]
+fn solve_refuses_early_positions() {
+    let output = magpie(&["solve", "f5d6c3"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("57 empty squares"), "{stderr}");
+}
+
+#[test]
+fn analyze_transcript() {
+    let output = stdout(&["analyze", "f5d6c3", "--depth", "2"]);
+    assert_eq!(output.lines().count(), 4);
+    assert!(output.lines().nth(1).unwrap().contains("f5"));
+}
+
+#[test]
+fn analyze_rejects_inconsistent_records() {
+    let ggf = stdout(&["convert", "f5d6c3", "--to", "ggf"]);
+
+    let output = magpie(&["analyze", &ggf.replace("W[D6]", "B[D6]")]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("but White is to move"), "{stderr}");
+
+    let output = magpie(&["analyze", &ggf.replace("W[D6]", "W[PA]")]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("illegal pass at move 2"), "{stderr}");
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert!(!magpie(&["convert", "f5a1", "--to", "ggf"]).status.success());
+    assert!(!magpie(&["frobnicate"]).status.success());
+    assert!(!magpie(&[]).status.success());
+}
+
+#[test]
+fn rejects_unknown_options() {
+    let output = magpie(&["analyze", "f5d6", "--depht", "5"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown option --depht"), "{stderr}");
+    assert!(stderr.contains("Usage: magpie"), "{stderr}");
+
+    assert!(!magpie(&["show", "f5", "--compcat"]).status.success());
+    assert!(!magpie(&["solve", "f5", "--moves"]).status.success());
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use common::{ShadowBoard, ShadowStone};
+use indoc::indoc;
+use magpie::othello::{Bitboard, Board, BoardError, DiagramError, Format, Position, Stone};
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn compact_roundtrip(board: ShadowBoard, stone: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let display = board.display().with_format(Format::Compact);
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    let display = display.with_stone(Stone::from(stone));
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board));
+}
+
+#[quickcheck]
+fn standard_roundtrip(board: ShadowBoard, stone: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let display = board.display().with_format(Format::Standard);
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    let display = display.with_stone(Stone::from(stone));
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board));
+}
+
+#[test]
+fn plain_grid() {
+    let board = Board::from_diagram(indoc! {"
+        B......W
+        --------
+        ........
+        ...wb...
+        ...bw...
+        ........
+        ........
+        W......B
+    "})
+    .unwrap();
+    assert_eq!(board.bits_for(Stone::Black).count_set(), 4);
+    assert_eq!(board.bits_for(Stone::White).count_set(), 4);
+    assert_eq!(board.stone_at("a1".try_into().unwrap()), Some(Stone::Black));
+    assert_eq!(board.stone_at("h1".try_into().unwrap()), Some(Stone::White));
+}
+
+#[test]
+fn rejects_malformed_diagrams() {
+    let errors = [
+        String::new(),
+        "........\n".repeat(7),
+        "........\n".repeat(9),
+        format!("{}.......\n", "........\n".repeat(7)),
+        format!("{}.......X\n", "........\n".repeat(7)),
+        format!("{}9 |........|\n", "........\n".repeat(7)),
+    ];
+    for diagram in errors {
+        assert!(
+            matches!(
+                Board::from_diagram(&diagram),
+                Err(BoardError::InvalidDiagram(_))
+            ),
+            "{diagram}"
+        );
+    }
+}
+
+#[test]
+fn diagram_errors_describe_the_problem() {
+    let error = |diagram: &str| match Board::from_diagram(diagram) {
+        Err(BoardError::InvalidDiagram(e)) => e,
+        result => panic!("unexpected {result:?}"),
+    };
+    assert_eq!(error(&"........\n".repeat(7)), DiagramError::RankCount(7));
+    assert_eq!(error(&"........\n".repeat(9)), DiagramError::TooManyRanks);
+    assert_eq!(DiagramError::TooManyRanks.to_string(), "more than 8 ranks");
+    assert_eq!(
+        DiagramError::RankCount(7).to_string(),
+        "expected 8 ranks, found 7"
+    );
+    assert_eq!(
+        error(&format!("{}.......\n", "........\n".repeat(7))),
+        DiagramError::SquareCount(8)
+    );
+    assert_eq!(
+        error(&format!("{}.......X\n", "........\n".repeat(7))),
+        DiagramError::InvalidSquare(8)
+    );
+    assert_eq!(
+        error(&format!("{}9 |........|\n", "........\n".repeat(7))),
+        DiagramError::RankLabel(8)
+    );
+}
+
+#[quickcheck]
+fn unicode_roundtrip(board: ShadowBoard, stone: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let display = board.display().with_format(Format::Unicode);
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    let display = display.with_stone(Stone::from(stone));
+    assert_eq!(Board::from_diagram(&display.to_string()), Ok(board));
+}
+
+#[quickcheck]
+fn highlighted_roundtrip(board: ShadowBoard, flipped: u64) {
+    let board = Board::try_from(board).unwrap();
+    let last_move = Position::try_from(flipped & flipped.wrapping_neg()).ok();
+    let flipped = Bitboard::from(flipped);
+    for format in [Format::Standard, Format::Unicode] {
+        let mut display = board.display().with_format(format).with_flipped(flipped);
+        if let Some(pos) = last_move {
+            display = display.with_last_move(pos);
+        }
+        assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    }
+}
+
+#[quickcheck]
+fn perspective_roundtrip(board: ShadowBoard, perspective: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let perspective = Stone::from(perspective);
+    for format in [Format::Compact, Format::Standard, Format::Unicode] {
+        let display = board
+            .display()
+            .with_format(format)
+            .with_perspective(perspective);
+        assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    }
+}
+
+#[quickcheck]
+fn without_coordinates_roundtrip(board: ShadowBoard) {
+    let board = Board::try_from(board).unwrap();
+    for format in [Format::Compact, Format::Standard, Format::Unicode] {
+        let display = board.display().with_format(format).with_coordinates(false);
+        assert_eq!(Board::from_diagram(&display.to_string()), Ok(board.clone()));
+    }
+}
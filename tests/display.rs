@@ -1,5 +1,7 @@
 use indoc::indoc;
-use magpie::othello::{Board, Format, Stone};
+use magpie::othello::{
+    Bitboard, Board, BoardDisplay, Format, Glyphs, MoveDiagram, Position, Stone,
+};
 
 #[test]
 fn display_opening_with_stone_format_standard() {
@@ -102,3 +104,214 @@ fn display_opening_format_compact() {
       "};
     assert_eq!(expected, result);
 }
+
+fn after_d3() -> (Board, Position, Bitboard) {
+    let before = Board::standard();
+    let pos = Position::try_from("d3").unwrap();
+    let mut after = before.clone();
+    after.play(Stone::Black, pos);
+    let flipped = before.bits_for(Stone::White) & after.bits_for(Stone::Black);
+    (after, pos, flipped)
+}
+
+#[test]
+fn display_last_move_format_unicode() {
+    let (board, pos, flipped) = after_d3();
+    let result = board
+        .display()
+        .with_format(Format::Unicode)
+        .with_stone(Stone::White)
+        .with_last_move(pos)
+        .with_flipped(flipped)
+        .to_string();
+    let expected = indoc! {"
+          A   B   C   D   E   F   G   H
+        ┌───┬───┬───┬───┬───┬───┬───┬───┐
+      1 │   │   │   │   │   │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      2 │   │   │   │   │   │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      3 │   │   │ · │[●]│ · │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      4 │   │   │   │(●)│ ● │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      5 │   │   │ · │ ● │ ○ │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      6 │   │   │   │   │   │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      7 │   │   │   │   │   │   │   │   │
+        ├───┼───┼───┼───┼───┼───┼───┼───┤
+      8 │   │   │   │   │   │   │   │   │
+        └───┴───┴───┴───┴───┴───┴───┴───┘
+     "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_last_move_format_standard() {
+    let (board, pos, flipped) = after_d3();
+    let result = board
+        .display()
+        .with_last_move(pos)
+        .with_flipped(flipped)
+        .to_string();
+    assert!(result.contains("3 |   |   |   |[B]|   |   |   |   |"));
+    assert!(result.contains("4 |   |   |   |(B)| B |   |   |   |"));
+}
+
+#[test]
+fn display_last_move_format_compact() {
+    let (board, pos, flipped) = after_d3();
+    let display = board.display().with_format(Format::Compact);
+    let highlighted = display.with_last_move(pos).with_flipped(flipped);
+    assert_eq!(display.to_string(), highlighted.to_string());
+}
+
+#[test]
+fn display_last_move_format_ansi() {
+    let (board, pos, flipped) = after_d3();
+    let result = board
+        .display()
+        .with_format(Format::Ansi)
+        .with_last_move(pos)
+        .with_flipped(flipped)
+        .to_string();
+    let lines: Vec<_> = result.lines().collect();
+    assert_eq!(lines.len(), 9);
+    assert_eq!(lines[0], "   A B C D E F G H");
+    assert!(lines[3].contains("\x1b[43;30m ●"));
+    assert!(lines[4].contains("\x1b[102;30m ●"));
+    assert!(lines[5].contains("\x1b[42;97m ○"));
+    assert!(lines[1..].iter().all(|line| line.ends_with("\x1b[0m")));
+}
+
+#[test]
+fn move_diagram_format_standard() {
+    let moves = ["f5", "d6", "c3", "d3", "c4", "f4", "c5", "b3", "c2", "e6"];
+    let moves = moves.map(|notation| Position::try_from(notation).unwrap());
+    let result = MoveDiagram::new(&Board::standard(), moves).to_string();
+    let expected = indoc! {"
+          A   B   C   D   E   F   G   H
+        +---+---+---+---+---+---+---+---+
+      1 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      2 |   |   | 9 |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      3 |   | 8 | 3 | 4 |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      4 |   |   | 5 | W | B | 6 |   |   |
+        +---+---+---+---+---+---+---+---+
+      5 |   |   | 7 | B | W | 1 |   |   |
+        +---+---+---+---+---+---+---+---+
+      6 |   |   |   | 2 |10 |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      7 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      8 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+     "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn move_diagram_format_compact() {
+    let moves = ["f5", "d6", "c3"].map(|notation| Position::try_from(notation).unwrap());
+    let result = MoveDiagram::new(&Board::standard(), moves)
+        .with_format(Format::Compact)
+        .to_string();
+    let expected = indoc! {"
+            A  B  C  D  E  F  G  H
+        1   .  .  .  .  .  .  .  .
+        2   .  .  .  .  .  .  .  .
+        3   .  .  3  .  .  .  .  .
+        4   .  .  .  W  B  .  .  .
+        5   .  .  .  B  W  1  .  .
+        6   .  .  .  2  .  .  .  .
+        7   .  .  .  .  .  .  .  .
+        8   .  .  .  .  .  .  .  .
+    "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_white_perspective_format_compact() {
+    let (board, _, _) = after_d3();
+    let result = board
+        .display()
+        .with_format(Format::Compact)
+        .with_stone(Stone::White)
+        .with_perspective(Stone::White)
+        .to_string();
+    let expected = indoc! {"
+         HGFEDCBA
+        +--------+
+      8 |........|
+      7 |........|
+      6 |........|
+      5 |...WB*..|
+      4 |...BB...|
+      3 |...*B*..|
+      2 |........|
+      1 |........|
+        +--------+
+     "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_custom_glyphs_without_coordinates() {
+    let board = Board::standard();
+    let glyphs = Glyphs {
+        black: 'X',
+        white: 'O',
+        empty: '-',
+        legal_move: '?',
+    };
+    let result = board
+        .display()
+        .with_format(Format::Compact)
+        .with_stone(Stone::Black)
+        .with_glyphs(glyphs)
+        .with_coordinates(false)
+        .to_string();
+    let expected = indoc! {"
+        +--------+
+        |--------|
+        |--------|
+        |---?----|
+        |--?OX---|
+        |---XO?--|
+        |----?---|
+        |--------|
+        |--------|
+        +--------+
+    "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_side_by_side() {
+    let (after, pos, flipped) = after_d3();
+    let before = Board::standard();
+    let result = BoardDisplay::side_by_side(
+        &[
+            before.display().with_format(Format::Compact),
+            after
+                .display()
+                .with_format(Format::Ansi)
+                .with_last_move(pos)
+                .with_flipped(flipped),
+            before.display().with_format(Format::Compact),
+        ],
+        2,
+    );
+    let lines: Vec<_> = result.lines().collect();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[0], "   ABCDEFGH      A B C D E F G H      ABCDEFGH");
+    // The escape codes of the colored board do not count towards its width
+    assert!(lines[8].ends_with("\x1b[0m  7 |........|"));
+    // Shorter boards are padded below
+    let padding = " ".repeat(2 + 19 + 2);
+    assert_eq!(lines[9], format!("8 |........|{padding}8 |........|"));
+    assert_eq!(lines[10], format!("  +--------+{padding}  +--------+"));
+}
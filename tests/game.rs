@@ -1,7 +1,7 @@
 mod common;
 
 use common::ShadowGame;
-use magpie::othello::{Bitboard, Game, Position};
+use magpie::othello::{Action, Bitboard, Board, Game, GameError, Position, Score, Status, Stone};
 use quickcheck_macros::quickcheck;
 
 #[quickcheck]
@@ -60,3 +60,83 @@ fn illegal_moves_should_be_illegal(game: ShadowGame) {
 
     assert!(!failed);
 }
+
+#[quickcheck]
+fn status_concludes_when_neither_can_move(game: ShadowGame) {
+    let game = Game::try_from(game).unwrap();
+    let board = game.board();
+    let stuck =
+        board.moves_for(Stone::Black).is_empty() && board.moves_for(Stone::White).is_empty();
+
+    assert_eq!(game.is_over(), stuck);
+    assert_eq!(game.status() != Status::Progressing, stuck);
+}
+
+#[quickcheck]
+fn auto_pass_leaves_a_move_to_make(game: ShadowGame) {
+    let game = Game::try_from(game).unwrap().with_auto_pass(true);
+
+    for pos in game.moves().hot_bits() {
+        let mut next = game.clone();
+        next.play(pos).unwrap();
+        assert!(next.is_over() || !next.moves().is_empty());
+    }
+}
+
+#[test]
+fn full_board_is_over_without_passing() {
+    let board = Board::try_from((Bitboard::FILLED.raw(), 0)).unwrap();
+    let game = Game::from_state(board, Stone::White, false).unwrap();
+    assert_eq!(game.status(), Status::Win(Stone::Black));
+    assert_eq!(
+        game.score(),
+        Score {
+            black: 64,
+            white: 0
+        }
+    );
+}
+
+#[quickcheck]
+fn pass_only_without_moves(game: ShadowGame) {
+    let game = Game::try_from(game).unwrap();
+    let mut passed = game.clone();
+
+    match passed.pass() {
+        Ok(()) => {
+            assert!(game.moves().is_empty());
+            assert_eq!(passed.current_turn(), game.current_turn().flip());
+            assert!(passed.passed_last_turn());
+        }
+        Err(e) => {
+            assert_eq!(e, GameError::IllegalPass);
+            assert!(!game.moves().is_empty());
+            assert_eq!(passed, game);
+        }
+    }
+}
+
+#[quickcheck]
+fn enforced_apply_matches_pass(game: ShadowGame) {
+    let game = Game::try_from(game).unwrap();
+    let mut checked = game.clone();
+    let mut enforced = game.clone().with_enforced_passes(true);
+    let mut unchecked = game.clone();
+
+    assert_eq!(enforced.apply(Action::Pass), checked.pass());
+    assert!(unchecked.apply(Action::Pass).is_ok());
+    assert_eq!(unchecked.current_turn(), game.current_turn().flip());
+}
+
+#[test]
+fn equality_ignores_settings() {
+    let game = Game::new();
+    let configured = Game::new().with_auto_pass(true);
+    assert_eq!(game, configured);
+    let configured = Game::new().with_enforced_passes(true);
+    assert_eq!(game, configured);
+
+    let mut passed = Game::new();
+    passed.pass_turn();
+    assert_ne!(game, passed);
+}
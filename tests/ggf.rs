@@ -0,0 +1,152 @@
+use magpie::ggf::{self, GgfError, GgfGame, GgfMove};
+use magpie::othello::{Bitboard, Board, Game, Position, Stone};
+
+const START: &str = "8 ---------------------------O*------*O--------------------------- *";
+
+#[test]
+fn parse_standard_board() {
+    let (board, stone) = ggf::parse_board(START).unwrap();
+    assert_eq!(board, Board::standard());
+    assert_eq!(stone, Stone::Black);
+}
+
+#[test]
+fn parse_board_split_into_ranks() {
+    let text = "8 -------- -------- -------- ---O*--- ---*O--- -------- -------- -------- O";
+    let (board, stone) = ggf::parse_board(text).unwrap();
+    assert_eq!(board, Board::standard());
+    assert_eq!(stone, Stone::White);
+}
+
+#[test]
+fn parse_board_rejects_invalid_input() {
+    assert_eq!(ggf::parse_board("10 --- *"), Err(GgfError::InvalidBoard));
+    assert_eq!(ggf::parse_board("8 ----- *"), Err(GgfError::InvalidBoard));
+    let bad_square = START.replace('*', "?");
+    assert_eq!(ggf::parse_board(&bad_square), Err(GgfError::InvalidBoard));
+}
+
+#[test]
+fn parse_record_with_tags_and_moves() {
+    let text = format!(
+        "(;GM[Othello]PC[GGS/os]PB[alice]PW[bob]RE[+2.000]TI[05:00]BO[{START}]B[f5//1.5]W[d6/-1.2];)"
+    );
+    let record: GgfGame = text.parse().unwrap();
+    assert_eq!(record.tag("PB"), Some("alice"));
+    assert_eq!(record.tag("RE"), Some("+2.000"));
+    assert_eq!(record.board(), Board::standard());
+    assert_eq!(record.first_player(), Stone::Black);
+
+    let moves = record.moves();
+    assert_eq!(moves.len(), 2);
+    assert_eq!(moves[0].position(), Some(Position::try_from("f5").unwrap()));
+    assert_eq!(moves[0].time(), Some(1.5));
+    assert_eq!(moves[1].stone(), Stone::White);
+    assert_eq!(moves[1].eval(), Some(-1.2));
+}
+
+#[test]
+fn parse_record_rejects_invalid_syntax() {
+    assert_eq!(
+        "GM[Othello]".parse::<GgfGame>(),
+        Err(GgfError::InvalidSyntax)
+    );
+    assert_eq!(
+        "(;GM[Othello;)".parse::<GgfGame>(),
+        Err(GgfError::InvalidSyntax)
+    );
+    assert_eq!(
+        "(;GM[Othello];)".parse::<GgfGame>(),
+        Err(GgfError::InvalidBoard)
+    );
+}
+
+#[test]
+fn record_roundtrip() {
+    let mut record = GgfGame::new(Board::standard(), Stone::Black);
+    record.set_tag("PB", "alice");
+    record.push_move(GgfMove::new(Stone::Black, Position::try_from("f5").unwrap()).with_eval(0.5));
+    record.push_move(GgfMove::new(Stone::White, Position::try_from("f6").unwrap()).with_time(3.0));
+
+    let parsed: GgfGame = record.to_string().parse().unwrap();
+    assert_eq!(parsed, record);
+}
+
+#[test]
+fn replay_inserts_omitted_passes() {
+    // Black has no legal moves, so White moves first even though Black is to
+    // move according to the record
+    let board = format!("8 {}O*{} *", "-".repeat(56), "-".repeat(6));
+    let text = format!("(;GM[Othello]BO[{board}]W[C8];)");
+    let game = text.parse::<GgfGame>().unwrap().to_game().unwrap();
+    assert_eq!(game.bits_for(Stone::White).count_set(), 3);
+}
+
+#[test]
+fn replay_rejects_illegal_moves() {
+    let text = format!("(;GM[Othello]BO[{START}]B[A1];)");
+    let result = text.parse::<GgfGame>().unwrap().to_game();
+    assert!(matches!(result, Err(GgfError::IllegalMove(_))));
+
+    let text = format!("(;GM[Othello]BO[{START}]W[F5];)");
+    let result = text.parse::<GgfGame>().unwrap().to_game();
+    assert!(matches!(result, Err(GgfError::IllegalMove(_))));
+}
+
+#[test]
+fn replay_matches_game() {
+    let text = format!("(;GM[Othello]BO[{START}]B[F5]W[D6]B[C3]W[PA];)");
+    let game = text.parse::<GgfGame>().unwrap().to_game().unwrap();
+
+    let mut expected = Game::new();
+    for square in ["F5", "D6", "C3"] {
+        expected.play(Position::try_from(square).unwrap()).unwrap();
+    }
+    expected.pass_turn();
+    assert_eq!(game, expected);
+}
+
+#[test]
+fn transcript_matches_record() {
+    let record = GgfGame::from_transcript("F5 d6 c3 PA").unwrap();
+    let text = format!("(;GM[Othello]BO[{START}]B[F5]W[D6]B[C3]W[PA];)");
+    assert_eq!(record, text.parse().unwrap());
+}
+
+#[test]
+fn transcript_rejects_invalid_moves() {
+    assert_eq!(
+        GgfGame::from_transcript("f5d"),
+        Err(GgfError::InvalidMove("d".to_string()))
+    );
+    assert_eq!(
+        GgfGame::from_transcript("f5ü6"),
+        Err(GgfError::InvalidMove("ü6".to_string()))
+    );
+    assert!(matches!(
+        GgfGame::from_transcript("f5a1"),
+        Err(GgfError::IllegalMove(_))
+    ));
+}
+
+#[test]
+fn move_diagram_skips_passes() {
+    let record = GgfGame::from_transcript("f5d6c3--").unwrap();
+    let diagram = record.move_diagram();
+    let numbered: Vec<_> = Bitboard::FILLED
+        .hot_bits()
+        .filter_map(|pos| {
+            diagram
+                .number_at(pos)
+                .map(|number| (pos.to_notation(), number))
+        })
+        .collect();
+    assert_eq!(
+        numbered,
+        [
+            ("c3".to_string(), 3),
+            ("f5".to_string(), 1),
+            ("d6".to_string(), 2)
+        ]
+    );
+}
@@ -0,0 +1,192 @@
+use magpie::ggs::{Client, Clock, Command, GgsError, Message};
+use magpie::othello::{Position, Stone};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    thread,
+    time::Duration,
+};
+
+const START: &str = "8 ---------------------------O*------*O--------------------------- *";
+
+fn record(moves: &str) -> String {
+    format!("(;GM[Othello]PC[GGS/os]PB[alice]PW[bob]TI[05:00//01:00]BO[{START}]{moves};)")
+}
+
+#[test]
+fn parse_match_request() {
+    let line = "/os: + .12 1520.5 alice 1480.0 bob 8 05:00/00:02/01:00";
+    let Message::MatchRequest(request) = line.parse().unwrap() else {
+        panic!("expected a match request");
+    };
+    assert_eq!(request.id, ".12");
+    assert_eq!(request.from.name, "alice");
+    assert_eq!(request.from.rating, 1520.5);
+    assert_eq!(request.to.name, "bob");
+    assert_eq!(request.variant, "8");
+    assert_eq!(request.clock.remaining, Duration::from_secs(300));
+    assert_eq!(request.clock.increment, Duration::from_secs(2));
+    assert_eq!(request.clock.extension, Duration::from_secs(60));
+}
+
+#[test]
+fn parse_match_end() {
+    let line = "/os: - match .27 1520.0 alice 1480.5 bob 8 +12.000";
+    let Message::MatchEnd(info, score) = line.parse().unwrap() else {
+        panic!("expected a match end");
+    };
+    assert_eq!(info.id, ".27");
+    assert_eq!(info.black.name, "alice");
+    assert_eq!(score, 12.0);
+}
+
+#[test]
+fn parse_join_and_update() {
+    let line = format!("/os: join .27 {}", record("B[F5]"));
+    let Message::Join(update) = line.parse().unwrap() else {
+        panic!("expected a join");
+    };
+    assert_eq!(update.id, ".27");
+    assert_eq!(update.game.current_turn(), Stone::White);
+    assert_eq!(update.record.tag("PB"), Some("alice"));
+    assert_eq!(update.clocks, None);
+
+    let line = format!(
+        "/os: update .27 04:55 05:00//01:00 {}",
+        record("B[F5]W[D6]")
+    );
+    let Message::Update(update) = line.parse().unwrap() else {
+        panic!("expected an update");
+    };
+    assert_eq!(update.game.current_turn(), Stone::Black);
+    let (black, white) = update.clocks.unwrap();
+    assert_eq!(black.remaining, Duration::from_secs(295));
+    assert_eq!(white.extension, Duration::from_secs(60));
+}
+
+#[test]
+fn parse_unknown_message() {
+    let line = "/os: something else";
+    assert_eq!(line.parse(), Ok(Message::Other(line.to_string())));
+    let line = "alice: hello";
+    assert_eq!(line.parse(), Ok(Message::Other(line.to_string())));
+}
+
+#[test]
+fn parse_malformed_messages() {
+    let result = "/os: + match .27 abc alice 1480.5 bob 8".parse::<Message>();
+    assert!(matches!(result, Err(GgsError::InvalidMessage(_))));
+
+    let result = "/os: join .27 (;GM[Othello];)".parse::<Message>();
+    assert!(matches!(result, Err(GgsError::InvalidGame(_))));
+
+    let result = format!("/os: update .27 xx 05:00 {}", record("")).parse::<Message>();
+    assert!(matches!(result, Err(GgsError::InvalidClock(_))));
+}
+
+#[test]
+fn clock_roundtrip() {
+    for text in ["05:00", "05:00//01:00", "10:00/00:05/", "65:05/00:01/00:30"] {
+        let clock: Clock = text.parse().unwrap();
+        assert_eq!(clock.to_string().parse::<Clock>().unwrap(), clock);
+    }
+    assert_eq!(
+        "1:05:00".parse::<Clock>().unwrap().remaining,
+        Duration::from_secs(3900)
+    );
+    assert!("05:00/1/2/3".parse::<Clock>().is_err());
+    assert!("five".parse::<Clock>().is_err());
+}
+
+#[test]
+fn format_commands() {
+    let accept = Command::Accept(".12".to_string());
+    assert_eq!(accept.to_string(), "t /os accept .12");
+    let decline = Command::Decline(".12".to_string());
+    assert_eq!(decline.to_string(), "t /os decline .12");
+    let pass = Command::Play {
+        id: ".27".to_string(),
+        position: None,
+        eval: None,
+    };
+    assert_eq!(pass.to_string(), "t /os play .27 PA");
+    let login = Command::Login {
+        name: "magpie".to_string(),
+        password: "secret".to_string(),
+    };
+    assert_eq!(login.to_string(), "magpie\nsecret");
+}
+
+#[test]
+fn client_plays_against_mock_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        let mut read_line = || {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            line.trim_end().to_string()
+        };
+
+        assert_eq!(read_line(), "magpie");
+        assert_eq!(read_line(), "secret");
+        writeln!(writer, "/os: + .3 1500.0 alice 1500.0 magpie 8 05:00").unwrap();
+        assert_eq!(read_line(), "t /os accept .3");
+        writeln!(writer, "/os: + match .4 1500.0 magpie 1500.0 alice 8").unwrap();
+        writeln!(writer, "/os: join .4 {}", record("")).unwrap();
+        let played = read_line();
+        writeln!(writer).unwrap();
+        writeln!(
+            writer,
+            "/os: - match .4 1500.0 magpie 1500.0 alice 8 +64.000"
+        )
+        .unwrap();
+        played
+    });
+
+    let mut client = Client::connect(addr).unwrap();
+    client
+        .send(&Command::Login {
+            name: "magpie".to_string(),
+            password: "secret".to_string(),
+        })
+        .unwrap();
+
+    let Some(Message::MatchRequest(request)) = client.next_message().unwrap() else {
+        panic!("expected a match request");
+    };
+    client.send(&Command::Accept(request.id)).unwrap();
+
+    assert!(matches!(
+        client.next_message().unwrap(),
+        Some(Message::MatchStart(_))
+    ));
+    let Some(Message::Join(update)) = client.next_message().unwrap() else {
+        panic!("expected to join the game");
+    };
+    let game = client.game(&update.id).unwrap();
+    assert_eq!(game.current_turn(), Stone::Black);
+    let position = game.moves().hot_bits().next().unwrap();
+    client
+        .send(&Command::Play {
+            id: update.id.clone(),
+            position: Some(position),
+            eval: None,
+        })
+        .unwrap();
+
+    assert!(matches!(
+        client.next_message().unwrap(),
+        Some(Message::MatchEnd(_, _))
+    ));
+    assert!(client.game(&update.id).is_none());
+    assert!(client.next_message().unwrap().is_none());
+
+    let played = server.join().unwrap();
+    let square = played.strip_prefix("t /os play .4 ").unwrap();
+    assert_eq!(Position::try_from(square).unwrap(), position);
+}
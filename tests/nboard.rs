@@ -0,0 +1,127 @@
+use magpie::nboard::{Control, SearchEngine, Session};
+use magpie::othello::{Game, Position, Stone};
+use magpie::search::Search;
+
+const START: &str = "8 ---------------------------O*------*O--------------------------- *";
+
+fn run(session: &mut Session<SearchEngine>, input: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    session.run(input.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+fn session() -> Session<SearchEngine> {
+    Session::new(SearchEngine::new(Search::new(2)))
+}
+
+#[test]
+fn handshake_reports_name() {
+    let output = run(&mut session(), "nboard 2\n");
+    assert_eq!(output, vec!["set myname magpie"]);
+}
+
+#[test]
+fn ping_is_answered_with_pong() {
+    let output = run(&mut session(), "ping 42\n");
+    assert_eq!(output, vec!["pong 42"]);
+}
+
+#[test]
+fn unknown_commands_are_ignored() {
+    let output = run(&mut session(), "set contempt 0\nfoo bar\nping 1\n");
+    assert_eq!(output, vec!["pong 1"]);
+}
+
+#[test]
+fn set_game_replays_moves() {
+    let mut session = session();
+    let input = format!("set game (;GM[Othello]PB[a]PW[b]BO[{START}]B[F5]W[F6/0.5/1];)\n");
+    run(&mut session, &input);
+
+    let mut expected = Game::new();
+    expected.play(Position::try_from("F5").unwrap()).unwrap();
+    expected.play(Position::try_from("F6").unwrap()).unwrap();
+    assert_eq!(session.game(), &expected);
+}
+
+#[test]
+fn move_updates_game() {
+    let mut session = session();
+    run(&mut session, "move F5/1.00/2.5\nmove d6\n");
+    assert_eq!(session.game().current_turn(), Stone::Black);
+    assert_eq!(session.game().empty_squares().count_set(), 58);
+}
+
+#[test]
+fn illegal_move_reports_status() {
+    let mut session = session();
+    let output = run(&mut session, "move A1\n");
+    assert_eq!(output.len(), 1);
+    assert!(output[0].starts_with("status "));
+    assert_eq!(session.game(), &Game::new());
+}
+
+#[test]
+fn illegal_pass_reports_status() {
+    let mut session = session();
+    let output = run(&mut session, "move PA\n");
+    assert_eq!(output.len(), 1);
+    assert!(output[0].starts_with("status "));
+    assert_eq!(session.game(), &Game::new());
+}
+
+#[test]
+fn go_returns_legal_move() {
+    let output = run(&mut session(), "go\n");
+    assert_eq!(output.first().map(String::as_str), Some("status Thinking"));
+    assert_eq!(output.last().map(String::as_str), Some("status"));
+
+    let answer = output[1].strip_prefix("=== ").unwrap();
+    let square = answer.split('/').next().unwrap();
+    let pos = Position::try_from(square).unwrap();
+    assert!(Game::new().is_legal_move(pos));
+}
+
+#[test]
+fn go_passes_without_legal_moves() {
+    let mut session = session();
+    // Black has no legal moves, but White does
+    let board = format!("8 {}O*{} *", "-".repeat(56), "-".repeat(6));
+    run(
+        &mut session,
+        &format!("set game (;GM[Othello]BO[{board}];)\n"),
+    );
+    let output = run(&mut session, "go\n");
+    assert_eq!(output[1], "=== PA");
+}
+
+#[test]
+fn hint_lists_requested_moves() {
+    let output = run(&mut session(), "hint 3\n");
+    let searches: Vec<&String> = output
+        .iter()
+        .filter(|line| line.starts_with("search "))
+        .collect();
+    assert_eq!(searches.len(), 3);
+    assert_eq!(output.last().map(String::as_str), Some("status"));
+}
+
+#[test]
+fn learn_is_acknowledged() {
+    let output = run(&mut session(), "learn\n");
+    assert_eq!(output, vec!["learned"]);
+}
+
+#[test]
+fn quit_stops_reading() {
+    let mut session = session();
+    let output = run(&mut session, "quit\nping 1\n");
+    assert!(output.is_empty());
+
+    let mut buffer = Vec::new();
+    assert_eq!(session.handle("quit", &mut buffer).unwrap(), Control::Quit);
+}
@@ -1,6 +1,7 @@
 mod common;
 
 use common::ShadowBoard;
+use indoc::indoc;
 use magpie::othello::{Bitboard, Board, Position, Stone};
 use quickcheck_macros::quickcheck;
 
@@ -27,6 +28,40 @@ fn board_one_legal_move() -> Board {
     (black_pos, white_pos).try_into().unwrap()
 }
 
+#[test]
+fn from_diagram_matches_bitboards() {
+    let board = Board::from_diagram(indoc! {"
+        B...B...
+        .W..W..B
+        ..W.W.W.
+        ...WWW..
+        BWWW.WWB
+        ...WWW..
+        ..W.W.W.
+        .B..B..B
+    "})
+    .unwrap();
+    assert_eq!(board, board_one_legal_move());
+}
+
+#[test]
+fn from_diagram_matches_bitboards_on_edges() {
+    let board = Board::from_diagram(indoc! {"
+        BBBBBBBB
+        BBBBBBBB
+        BBBBBBBB
+        BBBBBBBB
+        WWWWWWWW
+        WWWWWWWW
+        WWWWWWWW
+        ........
+    "})
+    .unwrap();
+    let black = 0xff_ff_ff_ff_00_00_00_00;
+    let white = 0x00_00_00_00_ff_ff_ff_00;
+    assert_eq!(board, Board::try_from((black, white)).unwrap());
+}
+
 fn board_no_legal_moves() -> Board {
     let board = board_one_legal_move();
 
@@ -73,3 +108,36 @@ fn stone_at_consistency(board: ShadowBoard) {
 
     assert!(success);
 }
+
+#[test]
+fn const_evaluation_matches_runtime() {
+    const START: Board = Board::standard();
+    const MOVES: Bitboard = START.moves_for(Stone::Black);
+    const AFTER_F5: Board = {
+        let mut board = Board::standard();
+        let pos = match Position::from_raw(0x00_00_00_00_04_00_00_00) {
+            Some(pos) => pos,
+            None => panic!("F5 is a single square"),
+        };
+        board.play(Stone::Black, pos);
+        board
+    };
+
+    let mut board = Board::standard();
+    assert_eq!(MOVES, board.moves_for(Stone::Black));
+    board.play(Stone::Black, Position::try_from("f5").unwrap());
+    assert_eq!(AFTER_F5, board);
+}
+
+#[quickcheck]
+fn const_queries_agree(board: ShadowBoard) {
+    let board = Board::try_from(board).unwrap();
+    for stone in [Stone::Black, Stone::White] {
+        let bits = board.bits_for(stone);
+        assert_eq!(Bitboard::from_raw(bits.raw()), bits);
+    }
+    for pos in Position::ALL {
+        assert_eq!(Position::from_raw(pos.raw()), Some(pos));
+        assert_eq!(pos.bitboard(), Bitboard::from(pos));
+    }
+}
@@ -1,7 +1,7 @@
 mod common;
 
 use common::ShadowPosition;
-use magpie::othello::{Bitboard, Position};
+use magpie::othello::{Bitboard, Position, PositionError};
 use quickcheck_macros::quickcheck;
 
 #[quickcheck]
@@ -92,3 +92,47 @@ fn position_notation_identity() {
         assert_eq!(*n, identity);
     }
 }
+
+#[quickcheck]
+fn static_notation_matches_owned(position: ShadowPosition) {
+    let position = Position::try_from(position).unwrap();
+    assert_eq!(position.notation(), position.to_notation());
+    assert_eq!(Position::try_from(position.notation()), Ok(position));
+    let upper = position.notation().to_uppercase();
+    assert_eq!(Position::try_from(upper.as_str()), Ok(position));
+}
+
+#[quickcheck]
+fn display_roundtrip(position: ShadowPosition) {
+    let position = Position::try_from(position).unwrap();
+    assert_eq!(position.to_string(), position.to_notation());
+    assert_eq!(position.to_string().parse(), Ok(position));
+    assert_eq!(
+        format!("{position:#}"),
+        position.to_notation().to_uppercase()
+    );
+    assert_eq!(format!("{position:#}").parse(), Ok(position));
+}
+
+#[test]
+fn parse_rejects_invalid_notation() {
+    for text in ["", "a", "i1", "a9", "a0", "a10", "1a", "a1 "] {
+        assert_eq!(
+            text.parse::<Position>(),
+            Err(PositionError::InvalidNotation)
+        );
+    }
+}
+
+#[test]
+fn from_notation_covers_every_square() {
+    for (index, pos) in Position::ALL.iter().enumerate() {
+        let file = char::from(b'a' + u8::try_from(index % 8).unwrap());
+        let rank = index / 8 + 1;
+        let lower = format!("{file}{rank}");
+        let upper = lower.to_uppercase();
+        assert_eq!(Position::from_notation(&lower), Some(*pos));
+        assert_eq!(Position::from_notation(&upper), Some(*pos));
+        assert_eq!(pos.notation(), lower);
+    }
+}
@@ -0,0 +1,49 @@
+use magpie::othello::{Action, Game, GameError, GameRecord, Position, Stone};
+use quickcheck_macros::quickcheck;
+
+// Plays a game from the standard opening, picking moves with the choices,
+// and records every action along the way.
+fn play_recorded(choices: &[u8]) -> (Game, GameRecord) {
+    let mut game = Game::new();
+    let mut record = GameRecord::new();
+    for choice in choices {
+        if game.is_over() {
+            break;
+        }
+        let moves = game.moves();
+        let action = if moves.is_empty() {
+            Action::Pass
+        } else {
+            let index = usize::from(*choice) % usize::from(moves.count_set());
+            Action::Move(moves.hot_bits().nth(index).unwrap())
+        };
+        game.apply(action).unwrap();
+        record.push(action);
+    }
+    (game, record)
+}
+
+#[quickcheck]
+fn replay_matches_played_game(choices: Vec<u8>) {
+    let (game, record) = play_recorded(&choices);
+    assert_eq!(record.to_game(), Ok(game));
+}
+
+#[test]
+fn replay_rejects_illegal_moves() {
+    let mut record = GameRecord::new();
+    record.push(Action::Move(Position::try_from("a1").unwrap()));
+    assert_eq!(record.to_game(), Err(GameError::IllegalMove));
+}
+
+#[test]
+fn record_starts_from_game() {
+    let mut game = Game::new();
+    game.play(Position::try_from("f5").unwrap()).unwrap();
+    let mut record = GameRecord::from_start(&game).with_date("2024-05-01");
+    assert_eq!(record.first_player, Stone::White);
+
+    record.push(Action::Move(Position::try_from("d6").unwrap()));
+    game.play(Position::try_from("d6").unwrap()).unwrap();
+    assert_eq!(record.to_game(), Ok(game));
+}
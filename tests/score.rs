@@ -0,0 +1,59 @@
+mod common;
+
+use common::{ShadowBoard, ShadowStone};
+use magpie::othello::{Board, Score, ScoringRule, Stone};
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn raw_score_counts_stones(board: ShadowBoard) {
+    let board = Board::try_from(board).unwrap();
+    let score = Score::new(&board, ScoringRule::Raw);
+    assert_eq!(score.black, board.bits_for(Stone::Black).count_set());
+    assert_eq!(score.white, board.bits_for(Stone::White).count_set());
+}
+
+#[quickcheck]
+fn rules_agree_on_winner(board: ShadowBoard) {
+    let board = Board::try_from(board).unwrap();
+    let raw = Score::new(&board, ScoringRule::Raw);
+    let official = Score::new(&board, ScoringRule::EmptiesToWinner);
+    assert_eq!(raw.winner(), official.winner());
+}
+
+#[quickcheck]
+fn empties_are_awarded(board: ShadowBoard) {
+    let board = Board::try_from(board).unwrap();
+    let score = Score::new(&board, ScoringRule::EmptiesToWinner);
+    // Draws split the empty squares evenly, which works out since an odd
+    // number of empty squares means an odd number of stones, ruling out a
+    // draw
+    assert_eq!(u32::from(score.black) + u32::from(score.white), 64);
+}
+
+#[quickcheck]
+fn differential_is_antisymmetric(board: ShadowBoard, stone: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let stone = Stone::from(stone);
+    for rule in [ScoringRule::Raw, ScoringRule::EmptiesToWinner] {
+        let score = Score::new(&board, rule);
+        assert_eq!(score.differential(stone), -score.differential(stone.flip()));
+        assert_eq!(
+            i32::from(score.differential(stone)),
+            i32::from(score.for_stone(stone)) - i32::from(score.for_stone(stone.flip()))
+        );
+    }
+}
+
+#[test]
+fn draw_splits_empties() {
+    let board = Board::try_from((0x0c, 0x30)).unwrap();
+    let score = Score::new(&board, ScoringRule::EmptiesToWinner);
+    assert_eq!(
+        score,
+        Score {
+            black: 32,
+            white: 32
+        }
+    );
+    assert_eq!(score.to_string(), "32-32");
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::ShadowBoard;
+use magpie::othello::{Board, Stone};
+use magpie::search::{self, Search};
+use quickcheck_macros::quickcheck;
+
+#[test]
+fn best_move_prefers_corner() {
+    // Black on C3 can either take the A1 corner by flipping B2, or play E3
+    // by flipping D3
+    let black = 0x00_00_20_00_00_00_00_00;
+    let white = 0x00_40_10_00_00_00_00_00;
+    let board = Board::try_from((black, white)).unwrap();
+    assert_eq!(board.moves_for(Stone::Black).count_set(), 2);
+    let best = Search::new(1).best_move(&board, Stone::Black).unwrap();
+    assert_eq!(best.position.to_notation(), "a1");
+}
+
+#[test]
+fn best_move_none_without_moves() {
+    let board = Board::empty();
+    assert!(Search::new(3).best_move(&board, Stone::Black).is_none());
+    assert!(
+        Search::new(3)
+            .evaluate_moves(&board, Stone::Black)
+            .is_empty()
+    );
+}
+
+#[test]
+fn solve_finished_board() {
+    let board = Board::try_from((u64::MAX << 32, u64::MAX >> 32)).unwrap();
+    assert_eq!(search::solve(&board, Stone::Black), 0);
+
+    let board = Board::try_from((u64::MAX << 16, u64::MAX >> 48)).unwrap();
+    assert_eq!(search::solve(&board, Stone::Black), 32);
+    assert_eq!(search::solve(&board, Stone::White), -32);
+}
+
+#[test]
+fn evaluations_are_sorted() {
+    let evaluations = Search::new(3).evaluate_moves(&Board::standard(), Stone::Black);
+    assert_eq!(evaluations.len(), 4);
+    assert!(evaluations.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+#[test]
+fn endgame_search_is_exact() {
+    // A full board minus the last rank, solved from both sides
+    let black = 0xff_ff_ff_ff_00_00_00_00;
+    let white = 0x00_00_00_00_ff_ff_ff_00;
+    let board = Board::try_from((black, white)).unwrap();
+    let search = Search::new(1).with_endgame(8);
+    let score = search.score(&board, Stone::Black);
+    assert_eq!(score, search::solve(&board, Stone::Black) * 100);
+}
+
+#[quickcheck]
+fn best_move_is_legal(board: ShadowBoard) -> bool {
+    let board = Board::try_from(board).unwrap();
+    let result = Search::new(2)
+        .with_endgame(0)
+        .best_move(&board, Stone::Black);
+    match result {
+        Some(evaluation) => board.is_legal_move(Stone::Black, evaluation.position),
+        None => board.moves_for(Stone::Black).is_empty(),
+    }
+}
+
+#[test]
+fn perft_matches_known_values() {
+    let expected = [1, 4, 12, 56, 244, 1396, 8200, 55092];
+    for (depth, expected) in (0..).zip(expected) {
+        assert_eq!(
+            search::perft(&Board::standard(), Stone::Black, depth),
+            expected
+        );
+    }
+}
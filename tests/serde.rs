@@ -1,7 +1,13 @@
 mod common;
 
 use common::{ShadowBitboard, ShadowBoard, ShadowPosition, ShadowStone};
-use magpie::othello::{Bitboard, Board, Game, Position, Stone};
+use magpie::{
+    ggf::GgfError,
+    othello::{
+        Action, Bitboard, BitboardError, Board, BoardError, DiagramError, Format, Game, GameError,
+        GameRecord, Position, PositionError, SetupError, Status, Stone, StoneError,
+    },
+};
 use quickcheck::TestResult;
 use quickcheck_macros::quickcheck;
 use serde_json::Result;
@@ -122,3 +128,195 @@ fn game_rejects_invalid_board(
         TestResult::discard()
     }
 }
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Snapshot {
+    #[serde(with = "magpie::serde::notation")]
+    position: Position,
+    #[serde(with = "magpie::serde::notation")]
+    stone: Stone,
+    #[serde(with = "magpie::serde::notation")]
+    board: Board,
+    #[serde(with = "magpie::serde::notation::option")]
+    last_move: Option<Position>,
+}
+
+#[quickcheck]
+fn notation_roundtrip(
+    position: ShadowPosition,
+    stone: ShadowStone,
+    board: ShadowBoard,
+    has_last_move: bool,
+) -> bool {
+    let position = Position::try_from(position).unwrap();
+    let snapshot = Snapshot {
+        position,
+        stone: Stone::from(stone),
+        board: Board::try_from(board).unwrap(),
+        last_move: has_last_move.then_some(position),
+    };
+    serde_roundtrip(&snapshot)
+}
+
+#[test]
+fn notation_is_human_readable() {
+    let snapshot = Snapshot {
+        position: Position::try_from("e6").unwrap(),
+        stone: Stone::White,
+        board: Board::standard(),
+        last_move: None,
+    };
+    let json = serde_json::to_value(&snapshot).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "position": "e6",
+            "stone": "W",
+            "board": "...........................WB......BW...........................",
+            "last_move": null,
+        })
+    );
+}
+
+#[test]
+fn notation_accepts_alternative_glyphs() {
+    let json = r#"{
+        "position": "E6",
+        "stone": "o",
+        "board": "---------------------------ox------xo---------------------------",
+        "last_move": "D3"
+    }"#;
+    let snapshot: Snapshot = serde_json::from_str(json).unwrap();
+    assert_eq!(snapshot.stone, Stone::White);
+    assert_eq!(snapshot.board, Board::standard());
+    assert_eq!(snapshot.last_move, Position::from_notation("d3"));
+}
+
+#[test]
+fn notation_rejects_invalid_text() {
+    for json in [
+        r#"{"position":"z9","stone":"B","board":"","last_move":null}"#,
+        r#"{"position":"a1","stone":"G","board":"","last_move":null}"#,
+        r#"{"position":"a1","stone":"B","board":"BW","last_move":null}"#,
+        r#"{"position":"a1","stone":"B","board":42,"last_move":null}"#,
+    ] {
+        assert!(serde_json::from_str::<Snapshot>(json).is_err(), "{json}");
+    }
+}
+
+#[test]
+fn game_default_representation() {
+    let game = Game::new().with_auto_pass(true).with_enforced_passes(true);
+    let json = serde_json::to_string(&game).unwrap();
+    assert_eq!(
+        json,
+        r#"{"board":{"black_stones":34628173824,"white_stones":68853694464},"next_player":"Black","passed_last_turn":false}"#
+    );
+    let decoded: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, game);
+    assert!(!decoded.auto_pass() && !decoded.enforces_passes());
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct GameSnapshot {
+    #[serde(with = "magpie::serde::notation")]
+    game: Game,
+}
+
+#[quickcheck]
+fn game_notation_roundtrip(board: ShadowBoard, stone: ShadowStone) -> bool {
+    let game =
+        Game::from_state(Board::try_from(board).unwrap(), Stone::from(stone), false).unwrap();
+    let json = serde_json::to_string(&GameSnapshot { game: game.clone() }).unwrap();
+    let decoded: GameSnapshot = serde_json::from_str(&json).unwrap();
+    decoded.game == game
+}
+
+#[test]
+fn game_notation_is_human_readable() {
+    let json = serde_json::to_value(GameSnapshot { game: Game::new() }).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "game": {
+                "board": "...........................WB......BW...........................",
+                "turn": "B",
+            }
+        })
+    );
+}
+
+#[test]
+fn status_roundtrip() {
+    for status in [
+        Status::Win(Stone::Black),
+        Status::Win(Stone::White),
+        Status::Draw,
+        Status::Progressing,
+    ] {
+        assert!(serde_roundtrip(&status));
+    }
+}
+
+#[test]
+fn format_roundtrip() {
+    for format in [
+        Format::Compact,
+        Format::Standard,
+        Format::Unicode,
+        Format::Ansi,
+    ] {
+        assert!(serde_roundtrip(&format));
+    }
+}
+
+#[test]
+fn error_roundtrip() {
+    assert!(serde_roundtrip(&BoardError::OverlappingPieces));
+    assert!(serde_roundtrip(&BoardError::InvalidDiagram(
+        DiagramError::RankCount(7)
+    )));
+    assert!(serde_roundtrip(&GameError::IllegalPass));
+    assert!(serde_roundtrip(&GameError::InvalidBoard(
+        BoardError::OutOfBounds
+    )));
+    assert!(serde_roundtrip(&PositionError::InvalidNotation));
+    assert!(serde_roundtrip(&StoneError::InvalidNotation));
+    assert!(serde_roundtrip(&BitboardError::InvalidNotation));
+    assert!(serde_roundtrip(&SetupError::InvalidHandicap(5)));
+    assert!(serde_roundtrip(&GgfError::InvalidMove("z9".to_string())));
+    assert!(serde_roundtrip(&GgfError::IllegalMove(
+        GameError::IllegalMove
+    )));
+}
+
+#[test]
+fn game_record_roundtrip() {
+    let mut record = GameRecord::new()
+        .with_players("Alice", "Bob")
+        .with_date("2024-05-01");
+    for notation in ["f5", "d6", "c3"] {
+        record.push(Action::Move(notation.parse().unwrap()));
+    }
+    record.result = Some(record.to_game().unwrap().score());
+    assert!(serde_roundtrip(&record));
+
+    let json = serde_json::to_value(&record).unwrap();
+    assert_eq!(json["black"], "Alice");
+    assert_eq!(
+        json["result"],
+        serde_json::json!({ "black": 5, "white": 2 })
+    );
+    assert_eq!(json["actions"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn game_record_metadata_is_optional() {
+    let json = serde_json::to_value(GameRecord::new()).unwrap();
+    let mut object = json.as_object().unwrap().clone();
+    for key in ["black", "white", "date", "result"] {
+        object.remove(key);
+    }
+    let record: GameRecord = serde_json::from_value(object.into()).unwrap();
+    assert_eq!(record, GameRecord::new());
+}
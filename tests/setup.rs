@@ -0,0 +1,69 @@
+use magpie::othello::{Board, Game, Opening, Setup, SetupError, Stone};
+use quickcheck_macros::quickcheck;
+
+fn discs(game: &Game) -> u8 {
+    64 - game.empty_squares().count_set()
+}
+
+#[test]
+fn parallel_opening_is_playable() {
+    let game = Setup::new()
+        .with_opening(Opening::Parallel)
+        .build()
+        .unwrap();
+    assert_eq!(game.moves().count_set(), 4);
+    assert_eq!(game.score().black, 2);
+    assert_ne!(game.board(), Board::standard());
+}
+
+#[test]
+fn handicap_fills_corners_in_order() {
+    let corners = ["a1", "h8", "h1", "a8"];
+    for count in 0..=4 {
+        let game = Setup::new()
+            .with_handicap(Stone::White, count)
+            .build()
+            .unwrap();
+        for (index, corner) in corners.iter().enumerate() {
+            let stone = game.stone_at((*corner).try_into().unwrap());
+            let expected = (index < usize::from(count)).then_some(Stone::White);
+            assert_eq!(stone, expected, "{corner} with {count} corners");
+        }
+    }
+}
+
+#[quickcheck]
+fn random_starts_reach_disc_count(seed: u64, count: u8) -> bool {
+    let count = 4 + count % 50;
+    let setup = Setup::new().with_random_discs(count).with_seed(seed);
+    let game = setup.build().unwrap();
+    discs(&game) == count && !game.is_over() && setup.build() == Ok(game)
+}
+
+#[test]
+fn random_starts_respect_handicap() {
+    let game = Setup::new()
+        .with_handicap(Stone::Black, 4)
+        .with_random_discs(20)
+        .with_seed(7)
+        .build()
+        .unwrap();
+    assert_eq!(discs(&game), 20);
+}
+
+#[test]
+fn invalid_setups_are_rejected() {
+    let setup = Setup::new().with_handicap(Stone::Black, 2);
+    assert_eq!(
+        setup.clone().with_random_discs(5).build(),
+        Err(SetupError::InvalidDiscCount(5))
+    );
+    assert_eq!(
+        setup.with_random_discs(65).build(),
+        Err(SetupError::InvalidDiscCount(65))
+    );
+    assert_eq!(
+        Setup::new().with_random_discs(64).with_seed(1).build(),
+        Err(SetupError::InvalidDiscCount(64))
+    );
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::ShadowPosition;
+use magpie::othello::{Bitboard, Position, Square};
+use quickcheck_macros::quickcheck;
+
+fn squares(notations: &[&str]) -> Bitboard {
+    notations
+        .iter()
+        .map(|notation| Position::try_from(*notation).unwrap())
+        .fold(Bitboard::EMPTY, |acc, pos| acc | pos)
+}
+
+#[quickcheck]
+fn square_position_roundtrip(pos: ShadowPosition) {
+    let pos = Position::try_from(pos).unwrap();
+    let square = Square::from(pos);
+    assert_eq!(Position::from(square), pos);
+    assert_eq!(Bitboard::from(square), Bitboard::from(pos));
+    assert_eq!(square.index(), pos.index());
+    assert_eq!(square.rank(), pos.rank());
+    assert_eq!(square.file(), pos.file());
+    assert_eq!(Square::from_index(square.index()), Some(square));
+}
+
+#[test]
+fn squares_match_notation() {
+    for (index, square) in Square::ALL.into_iter().enumerate() {
+        let notation = format!("{square:?}");
+        assert_eq!(Position::try_from(notation.as_str()), Ok(square.position()));
+        assert_eq!(usize::from(square.index()), index);
+        assert_eq!(Position::ALL[index], square.position());
+    }
+    assert_eq!(Square::from_index(u8::MAX), None);
+}
+
+#[test]
+fn all_positions_are_distinct() {
+    let all = Position::ALL
+        .iter()
+        .fold(Bitboard::EMPTY, |acc, pos| acc | *pos);
+    assert_eq!(all, Bitboard::FILLED);
+    assert!(Position::ALL.windows(2).all(|pair| pair[0] > pair[1]));
+}
+
+#[test]
+fn regions() {
+    assert_eq!(Bitboard::CORNERS, squares(&["a1", "h1", "a8", "h8"]));
+    assert_eq!(Bitboard::X_SQUARES, squares(&["b2", "g2", "b7", "g7"]));
+    assert_eq!(
+        Bitboard::C_SQUARES,
+        squares(&["b1", "g1", "a2", "h2", "a7", "h7", "b8", "g8"])
+    );
+    assert_eq!(Bitboard::CENTER, squares(&["d4", "e4", "d5", "e5"]));
+
+    let edges = Position::ALL
+        .into_iter()
+        .filter(|pos| [0, 7].contains(&pos.rank()) || [0, 7].contains(&pos.file()))
+        .fold(Bitboard::EMPTY, |acc, pos| acc | pos);
+    assert_eq!(Bitboard::EDGES, edges);
+    assert_eq!(
+        Bitboard::CORNERS.neighbors(),
+        Bitboard::X_SQUARES | Bitboard::C_SQUARES
+    );
+}
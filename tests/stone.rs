@@ -1,7 +1,23 @@
-use magpie::othello::Stone;
+use magpie::othello::{Stone, StoneError};
 
 #[test]
 fn stone_flip_equality() {
     let stone = Stone::Black;
     assert_eq!(stone, stone.flip().flip());
 }
+
+#[test]
+fn stone_display_roundtrip() {
+    for stone in [Stone::Black, Stone::White] {
+        assert_eq!(stone.to_string().parse(), Ok(stone));
+        assert_eq!(format!("{stone:#}").parse(), Ok(stone));
+        assert_eq!(stone.to_string().to_lowercase().parse(), Ok(stone));
+    }
+}
+
+#[test]
+fn stone_parse_rejects_unknown() {
+    for text in ["", "BW", "*", "blackish", " b"] {
+        assert_eq!(text.parse::<Stone>(), Err(StoneError::InvalidNotation));
+    }
+}
@@ -0,0 +1,38 @@
+use magpie::{
+    arbitrary::{board, position, reachable_board, reachable_game},
+    othello::{Bitboard, Board, Game, Position},
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn boards_are_valid(board in board()) {
+        prop_assert!(board.is_valid());
+    }
+
+    #[test]
+    fn reachable_boards_keep_the_center(board in reachable_board()) {
+        prop_assert!(board.is_valid());
+        prop_assert_eq!(board.empty_squares() & Bitboard::CENTER, 0);
+    }
+
+    #[test]
+    fn reachable_games_can_continue(mut game in reachable_game()) {
+        if !game.is_over() && game.moves().is_empty() {
+            prop_assert!(game.pass().is_ok());
+            prop_assert!(!game.moves().is_empty());
+        }
+    }
+
+    #[test]
+    fn positions_have_a_single_bit(pos in position()) {
+        prop_assert_eq!(pos.raw().count_ones(), 1);
+    }
+
+    #[test]
+    fn arbitrary_matches_strategies(board in any::<Board>(), game in any::<Game>(), pos in any::<Position>()) {
+        prop_assert_eq!(board.empty_squares() & Bitboard::CENTER, 0);
+        prop_assert!(game.board().is_valid());
+        prop_assert_eq!(Position::try_from(pos.raw()), Ok(pos));
+    }
+}
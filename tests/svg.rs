@@ -0,0 +1,118 @@
+mod common;
+
+use common::{ShadowBoard, ShadowStone};
+use magpie::ggf::{GgfGame, GgfMove};
+use magpie::othello::{Board, Game, Position, Stone};
+use magpie::svg::{Svg, Theme};
+use quickcheck_macros::quickcheck;
+
+fn count(svg: &str, pattern: &str) -> usize {
+    svg.matches(pattern).count()
+}
+
+#[quickcheck]
+fn draws_every_stone_and_move(board: ShadowBoard, stone: ShadowStone) {
+    let board = Board::try_from(board).unwrap();
+    let stone = Stone::from(stone);
+    let svg = Svg::new(&board).with_moves(stone).to_string();
+
+    let black = board.bits_for(Stone::Black).count_set() as usize;
+    let white = board.bits_for(Stone::White).count_set() as usize;
+    let moves = board.moves_for(stone).count_set() as usize;
+    assert_eq!(count(&svg, r##"fill="#000000" stroke"##), black);
+    assert_eq!(count(&svg, r##"fill="#ffffff" stroke"##), white);
+    assert_eq!(count(&svg, "<circle"), black + white + moves);
+}
+
+#[test]
+fn standalone_document() {
+    let svg = Svg::new(&Board::standard()).to_string();
+    assert!(svg.starts_with(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="360" height="360" viewBox="0 0 360 360">"#
+    ));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(count(&svg, "<line"), 14);
+    // Every file and rank label is written on both sides of the board
+    assert_eq!(count(&svg, "<text"), 32);
+    assert_eq!(count(&svg, ">A</text>"), 2);
+    assert_eq!(count(&svg, ">8</text>"), 2);
+}
+
+#[test]
+fn hides_coordinates() {
+    let svg = Svg::new(&Board::standard())
+        .with_coordinates(false)
+        .with_square_size(10)
+        .to_string();
+    assert!(svg.contains(r#"viewBox="0 0 80 80""#));
+    assert_eq!(count(&svg, "<text"), 0);
+}
+
+#[test]
+fn applies_theme() {
+    let theme = Theme {
+        board: "darkgreen".to_string(),
+        marker: "red".to_string(),
+        ..Theme::default()
+    };
+    let svg = Svg::from_game(&Game::new()).with_theme(theme).to_string();
+    assert_eq!(count(&svg, r#"fill="darkgreen""#), 1);
+    assert_eq!(count(&svg, r#"fill="red""#), 4);
+}
+
+#[test]
+fn escapes_theme_colors() {
+    let theme = Theme {
+        board: r#"red"/><script>alert(1)</script><rect fill="a&b"#.to_string(),
+        ..Theme::default()
+    };
+    let svg = Svg::new(&Board::standard()).with_theme(theme).to_string();
+    assert!(!svg.contains("<script>"));
+    assert_eq!(
+        count(
+            &svg,
+            r#"fill="red&quot;/&gt;&lt;script&gt;alert(1)&lt;/script&gt;&lt;rect fill=&quot;a&amp;b""#
+        ),
+        1
+    );
+}
+
+#[test]
+fn clamps_square_size() {
+    let svg = Svg::new(&Board::standard())
+        .with_moves(Stone::Black)
+        .with_move_number(Position::try_from("d4").unwrap(), 1)
+        .with_square_size(u32::MAX)
+        .to_string();
+    // Eight squares plus two margins of half a square each
+    assert_eq!(Svg::MAX_SQUARE_SIZE, 477_218_588);
+    assert!(svg.contains(r#"width="4294967292""#));
+}
+
+#[test]
+fn numbers_moves_of_record() {
+    let mut record = GgfGame::new(Board::standard(), Stone::Black);
+    for (stone, notation) in [
+        (Stone::Black, "f5"),
+        (Stone::White, "f6"),
+        (Stone::Black, "e6"),
+    ] {
+        let pos = Position::try_from(notation).unwrap();
+        record.push_move(GgfMove::new(stone, pos));
+    }
+    let svg = Svg::from_record(&record)
+        .unwrap()
+        .with_coordinates(false)
+        .to_string();
+
+    // F5 and E6 are black, F6 is white
+    assert!(svg.contains(r##"<text x="220" y="180" font-family="sans-serif" font-size="16" text-anchor="middle" dominant-baseline="central" fill="#ffffff">1</text>"##));
+    assert!(svg.contains(r##"x="220" y="220" font-family="sans-serif" font-size="16" text-anchor="middle" dominant-baseline="central" fill="#000000">2</text>"##));
+    assert!(svg.contains(r##"x="180" y="220" font-family="sans-serif" font-size="16" text-anchor="middle" dominant-baseline="central" fill="#ffffff">3</text>"##));
+    // White to move after the three moves
+    let game = record.to_game().unwrap();
+    assert_eq!(
+        count(&svg, "<circle") - 7,
+        game.moves().count_set() as usize
+    );
+}
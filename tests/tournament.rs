@@ -0,0 +1,264 @@
+use magpie::agent::{Action, Agent, GreedyAgent, MinimaxAgent, RandomAgent};
+use magpie::othello::{Board, Game, Position, ScoringRule, Stone};
+use magpie::tournament::{Hypothesis, Match, Sprt, Tally};
+
+/// Always passes, even when it has legal moves.
+struct Passer;
+
+impl Agent for Passer {
+    fn play(&mut self, _stone: Stone, _board: &Board) -> Action {
+        Action::Pass
+    }
+}
+
+/// Always plays on D4, which is never empty.
+struct IllegalMover;
+
+impl Agent for IllegalMover {
+    fn play(&mut self, _stone: Stone, _board: &Board) -> Action {
+        Action::Move(Position::try_from("d4").unwrap())
+    }
+}
+
+fn openings() -> Vec<Game> {
+    ["f5", "d3", "c4", "e6"]
+        .into_iter()
+        .map(|notation| {
+            let mut game = Game::new();
+            game.play(Position::try_from(notation).unwrap()).unwrap();
+            game
+        })
+        .collect()
+}
+
+#[test]
+fn plays_every_opening_with_both_colors() {
+    let result = Match::new()
+        .with_openings(openings())
+        .with_rounds(2)
+        .run(|| RandomAgent::with_seed(1), || RandomAgent::with_seed(2));
+
+    assert_eq!(result.games().len(), 16);
+    for (index, game) in result.games().iter().enumerate() {
+        assert_eq!(game.opening, (index / 2) % 4);
+        let first = if index.is_multiple_of(2) {
+            Stone::Black
+        } else {
+            Stone::White
+        };
+        assert_eq!(game.first, first);
+        assert!(!game.forfeit);
+    }
+}
+
+#[test]
+fn records_replay_to_final_position() {
+    let result = Match::new()
+        .with_openings(openings())
+        .run(|| GreedyAgent, || RandomAgent::with_seed(5));
+
+    for game in result.games() {
+        let replayed = game.record.to_game().unwrap();
+        assert!(replayed.is_over());
+        assert_eq!(
+            replayed.final_score(ScoringRule::EmptiesToWinner),
+            game.score
+        );
+        assert_eq!(game.score.black + game.score.white, 64);
+    }
+}
+
+#[test]
+fn parallel_matches_play_every_game() {
+    let sequential = Match::new()
+        .with_openings(openings())
+        .with_rounds(3)
+        .run(|| GreedyAgent, || MinimaxAgent::new(1));
+    let parallel = Match::new()
+        .with_openings(openings())
+        .with_rounds(3)
+        .with_threads(4)
+        .run(|| GreedyAgent, || MinimaxAgent::new(1));
+
+    // Deterministic agents play the same games regardless of scheduling
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn illegal_actions_forfeit() {
+    let result = Match::new().run(|| GreedyAgent, || Passer);
+    let tally = result.tally();
+    assert_eq!(
+        tally,
+        Tally {
+            wins: 2,
+            draws: 0,
+            losses: 0
+        }
+    );
+    assert!(result.games().iter().all(|game| game.forfeit));
+    assert_eq!(result.win_rate(), Some(1.0));
+}
+
+#[test]
+fn forfeits_award_all_discs_to_the_winner() {
+    let result = Match::new().run(|| GreedyAgent, || IllegalMover);
+    assert!(result.games().iter().all(|game| game.forfeit));
+    for game in result.games() {
+        assert_eq!(game.disc_difference(), 64);
+        assert_eq!(game.score.for_stone(game.winner.unwrap()), 64);
+    }
+    assert_eq!(result.disc_differential(), Some(64.0));
+}
+
+#[test]
+fn empty_match_has_no_statistics() {
+    let result = Match::new()
+        .with_openings([])
+        .run(|| GreedyAgent, || GreedyAgent);
+    assert!(result.games().is_empty());
+    assert_eq!(result.win_rate(), None);
+    assert_eq!(result.disc_differential(), None);
+    assert_eq!(result.elo(), None);
+}
+
+#[test]
+fn elo_is_symmetric() {
+    let tally = Tally {
+        wins: 40,
+        draws: 20,
+        losses: 60,
+    };
+    let mirrored = Tally {
+        wins: 60,
+        draws: 20,
+        losses: 40,
+    };
+    let elo = tally.elo().unwrap();
+    let mirrored = mirrored.elo().unwrap();
+    assert!((elo.difference + mirrored.difference).abs() < 1e-9);
+    assert!((elo.lower + mirrored.upper).abs() < 1e-9);
+    assert!((elo.upper + mirrored.lower).abs() < 1e-9);
+    assert!(elo.lower < elo.difference && elo.difference < elo.upper);
+    assert!(elo.upper < 0.0);
+}
+
+#[test]
+fn lopsided_tally_has_unbounded_interval() {
+    let tally = Tally {
+        wins: 9,
+        draws: 0,
+        losses: 1,
+    };
+    let elo = tally.elo().unwrap();
+    assert!(elo.difference.is_finite() && elo.difference > 0.0);
+    assert!(elo.lower.is_finite() && elo.lower < elo.difference);
+    assert_eq!(elo.upper, f64::INFINITY);
+
+    let mirrored = Tally {
+        wins: 1,
+        draws: 0,
+        losses: 9,
+    };
+    let mirrored = mirrored.elo().unwrap();
+    assert_eq!(mirrored.lower, f64::NEG_INFINITY);
+    assert!((elo.lower + mirrored.upper).abs() < 1e-9);
+}
+
+#[test]
+fn perfect_tally_has_infinite_elo() {
+    let won = Tally {
+        wins: 10,
+        draws: 0,
+        losses: 0,
+    };
+    let elo = won.elo().unwrap();
+    assert_eq!(elo.difference, f64::INFINITY);
+    assert_eq!(elo.lower, f64::INFINITY);
+    assert_eq!(elo.upper, f64::INFINITY);
+
+    let lost = Tally {
+        wins: 0,
+        draws: 0,
+        losses: 10,
+    };
+    let elo = lost.elo().unwrap();
+    assert_eq!(elo.difference, f64::NEG_INFINITY);
+    assert_eq!(elo.lower, f64::NEG_INFINITY);
+    assert_eq!(elo.upper, f64::NEG_INFINITY);
+}
+
+#[test]
+fn even_tally_has_no_elo_difference() {
+    let tally = Tally {
+        wins: 10,
+        draws: 5,
+        losses: 10,
+    };
+    assert_eq!(tally.elo().unwrap().difference, 0.0);
+}
+
+#[test]
+fn sprt_accepts_null_hypothesis() {
+    let sprt = Sprt::new(0.0, 20.0);
+    let tally = Tally {
+        wins: 300,
+        draws: 100,
+        losses: 600,
+    };
+    assert_eq!(sprt.decide(tally), Some(Hypothesis::Null));
+    assert!(sprt.llr(tally) < sprt.bounds().0);
+}
+
+#[test]
+fn sprt_needs_information() {
+    let sprt = Sprt::new(0.0, 20.0);
+    assert_eq!(sprt.llr(Tally::default()), 0.0);
+    assert_eq!(sprt.decide(Tally::default()), None);
+}
+
+#[test]
+fn sprt_decides_unanimous_tallies() {
+    let sprt = Sprt::new(0.0, 20.0);
+    let won = Tally {
+        wins: 1000,
+        draws: 0,
+        losses: 0,
+    };
+    assert_eq!(sprt.decide(won), Some(Hypothesis::Alternative));
+    let lost = Tally {
+        wins: 0,
+        draws: 0,
+        losses: 1000,
+    };
+    assert_eq!(sprt.decide(lost), Some(Hypothesis::Null));
+    let drawn = Tally {
+        wins: 0,
+        draws: 1000,
+        losses: 0,
+    };
+    assert_eq!(sprt.decide(drawn), Some(Hypothesis::Null));
+}
+
+#[test]
+fn sprt_stops_lopsided_match_early() {
+    let result = Match::new()
+        .with_openings(openings())
+        .with_rounds(200)
+        .with_sprt(Sprt::new(0.0, 200.0))
+        .run(|| GreedyAgent, || IllegalMover);
+    assert_eq!(result.decision(), Some(Hypothesis::Alternative));
+    assert!(result.games().len() < 1600);
+}
+
+#[test]
+fn sprt_stops_match_early() {
+    let result = Match::new()
+        .with_openings(openings())
+        .with_rounds(200)
+        .with_threads(2)
+        .with_sprt(Sprt::new(0.0, 200.0))
+        .run(|| MinimaxAgent::new(2), || RandomAgent::with_seed(9));
+    assert_eq!(result.decision(), Some(Hypothesis::Alternative));
+    assert!(result.games().len() < 1600);
+}
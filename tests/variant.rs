@@ -0,0 +1,282 @@
+use magpie::othello::{Action, Board, Game, GameBoard, GameError, Position, Stone};
+use magpie::search;
+use magpie::variant::{Bits, Coord, Size, Size6, Size10, VariantBoard, VariantGame};
+use quickcheck_macros::quickcheck;
+
+/// Maps the squares of a board to the column and row used by the mailbox.
+trait Squares: GameBoard {
+    const SIZE: i8;
+
+    fn square(col: i8, row: i8) -> Self::Square;
+
+    fn coords(square: Self::Square) -> (i8, i8);
+}
+
+impl<S: Size> Squares for VariantBoard<S> {
+    const SIZE: i8 = S::SIZE as i8;
+
+    fn square(col: i8, row: i8) -> Coord {
+        Coord::new(col as u8, row as u8)
+    }
+
+    fn coords(coord: Coord) -> (i8, i8) {
+        (coord.col as i8, coord.row as i8)
+    }
+}
+
+impl Squares for Board {
+    const SIZE: i8 = 8;
+
+    fn square(col: i8, row: i8) -> Position {
+        Position::try_from((row as u8, col as u8)).unwrap()
+    }
+
+    fn coords(pos: Position) -> (i8, i8) {
+        (pos.file() as i8, pos.rank() as i8)
+    }
+}
+
+/// A straightforward square-by-square board used as a reference.
+#[derive(Clone)]
+struct Mailbox {
+    size: i8,
+    squares: Vec<Option<Stone>>,
+}
+
+impl Mailbox {
+    fn from_board<B: Squares>(board: &B) -> Self {
+        let size = B::SIZE;
+        let squares = (0..size)
+            .flat_map(|row| (0..size).map(move |col| B::square(col, row)))
+            .map(|square| board.stone_at(square))
+            .collect();
+        Self { size, squares }
+    }
+
+    fn get(&self, col: i8, row: i8) -> Option<Option<Stone>> {
+        let inside = (0..self.size).contains(&col) && (0..self.size).contains(&row);
+        inside.then(|| self.squares[usize::try_from(row * self.size + col).unwrap()])
+    }
+
+    fn flips(&self, stone: Stone, col: i8, row: i8) -> Vec<(i8, i8)> {
+        if self.get(col, row) != Some(None) {
+            return Vec::new();
+        }
+        let mut flips = Vec::new();
+        for (dc, dr) in [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ] {
+            let mut run = Vec::new();
+            let (mut c, mut r) = (col + dc, row + dr);
+            while self.get(c, r) == Some(Some(stone.flip())) {
+                run.push((c, r));
+                c += dc;
+                r += dr;
+            }
+            if self.get(c, r) == Some(Some(stone)) {
+                flips.extend(run);
+            }
+        }
+        flips
+    }
+
+    fn moves(&self, stone: Stone) -> Vec<(i8, i8)> {
+        (0..self.size)
+            .flat_map(|row| (0..self.size).map(move |col| (col, row)))
+            .filter(|&(col, row)| !self.flips(stone, col, row).is_empty())
+            .collect()
+    }
+
+    fn play(&mut self, stone: Stone, col: i8, row: i8) {
+        for (c, r) in self.flips(stone, col, row) {
+            self.squares[usize::try_from(r * self.size + c).unwrap()] = Some(stone);
+        }
+        self.squares[usize::try_from(row * self.size + col).unwrap()] = Some(stone);
+    }
+
+    fn perft(&self, stone: Stone, passed: bool, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let moves = self.moves(stone);
+        if moves.is_empty() {
+            if passed {
+                1
+            } else {
+                self.perft(stone.flip(), true, depth - 1)
+            }
+        } else {
+            moves
+                .into_iter()
+                .map(|(col, row)| {
+                    let mut next = self.clone();
+                    next.play(stone, col, row);
+                    next.perft(stone.flip(), false, depth - 1)
+                })
+                .sum()
+        }
+    }
+}
+
+fn coords<B: Squares>(squares: B::Squares) -> Vec<(i8, i8)> {
+    let mut coords: Vec<(i8, i8)> = B::squares(squares).map(B::coords).collect();
+    coords.sort_by_key(|&(col, row)| (row, col));
+    coords
+}
+
+/// Plays random games, checking every position against the mailbox.
+fn check_random_games<B: Squares>(seed: u64) {
+    let mut state = seed;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        state >> 33
+    };
+
+    let mut game = Game::<B>::standard();
+    let mut mailbox = Mailbox::from_board(&game.board());
+    while !game.is_over() {
+        let stone = game.current_turn();
+        let expected = mailbox.moves(stone);
+        assert_eq!(coords::<B>(game.moves()), expected);
+
+        if expected.is_empty() {
+            game.pass().unwrap();
+            continue;
+        }
+        let (col, row) = expected[usize::try_from(next()).unwrap() % expected.len()];
+        game.play(B::square(col, row)).unwrap();
+        mailbox.play(stone, col, row);
+
+        assert_eq!(Mailbox::from_board(&game.board()).squares, mailbox.squares);
+    }
+    assert!(mailbox.moves(Stone::Black).is_empty());
+    assert!(mailbox.moves(Stone::White).is_empty());
+}
+
+#[quickcheck]
+fn size6_matches_mailbox(seed: u64) {
+    check_random_games::<VariantBoard<Size6>>(seed);
+}
+
+#[quickcheck]
+fn standard_board_matches_mailbox(seed: u64) {
+    check_random_games::<Board>(seed);
+}
+
+#[quickcheck]
+fn size10_matches_mailbox(seed: u64) {
+    check_random_games::<VariantBoard<Size10>>(seed);
+}
+
+#[test]
+fn size6_perft() {
+    let board = VariantBoard::<Size6>::standard();
+    let mailbox = Mailbox::from_board(&board);
+    let expected = [4, 12, 56, 244, 1364, 7604, 47740];
+    for (depth, expected) in (1..).zip(expected) {
+        assert_eq!(mailbox.perft(Stone::Black, false, depth), expected);
+        assert_eq!(search::perft(&board, Stone::Black, depth), expected);
+    }
+}
+
+#[test]
+fn standard_board_perft() {
+    let board = Board::standard();
+    let mailbox = Mailbox::from_board(&board);
+    let expected = [4, 12, 56, 244, 1396, 8200];
+    for (depth, expected) in (1..).zip(expected) {
+        assert_eq!(mailbox.perft(Stone::Black, false, depth), expected);
+        assert_eq!(search::perft(&board, Stone::Black, depth), expected);
+    }
+}
+
+#[test]
+fn size10_perft() {
+    // The counts match the standard board until the edges are reached
+    let board = VariantBoard::<Size10>::standard();
+    let mailbox = Mailbox::from_board(&board);
+    let expected = [4, 12, 56, 244, 1396, 8200, 55180, 392_268];
+    for (depth, expected) in (1..).zip(expected) {
+        assert_eq!(mailbox.perft(Stone::Black, false, depth), expected);
+        assert_eq!(search::perft(&board, Stone::Black, depth), expected);
+    }
+}
+
+#[test]
+fn variant_games_share_the_rules() {
+    // Black can outflank White on B1 by playing C1 and on B6 by playing C6,
+    // while White has no moves at all
+    let mut board = VariantBoard::<Size6>::empty();
+    board.place_stone_unchecked(Stone::Black, "a1".parse().unwrap());
+    board.place_stone_unchecked(Stone::White, "b1".parse().unwrap());
+    board.place_stone_unchecked(Stone::Black, "a6".parse().unwrap());
+    board.place_stone_unchecked(Stone::White, "b6".parse().unwrap());
+    let mut game = VariantGame::from_state(board, Stone::Black, false)
+        .unwrap()
+        .with_auto_pass(true)
+        .with_enforced_passes(true);
+
+    assert_eq!(game.apply(Action::Pass), Err(GameError::IllegalPass));
+    game.apply(Action::Move("c1".parse().unwrap())).unwrap();
+    assert_eq!(game.current_turn(), Stone::Black);
+    assert!(game.passed_last_turn());
+    assert!(!game.is_over());
+}
+
+#[test]
+fn moves_never_leave_the_board() {
+    let board = VariantBoard::<Size6>::standard();
+    for stone in [Stone::Black, Stone::White] {
+        assert_eq!(board.moves_for(stone) & !Size6::FULL, 0);
+    }
+    let board = VariantBoard::<Size10>::standard();
+    assert_eq!(board.empty_squares().count(), 96);
+}
+
+#[test]
+fn boards_are_valid() {
+    assert!(VariantBoard::<Size6>::standard().is_valid());
+    assert!(VariantBoard::<Size10>::empty().is_valid());
+    let mut board = VariantBoard::<Size6>::empty();
+    board.place_stone_unchecked(Stone::Black, Coord::new(6, 0));
+    board.place_stone_unchecked(Stone::White, Coord::new(0, 6));
+    assert!(board.is_valid());
+    assert_eq!(board, VariantBoard::empty());
+}
+
+#[test]
+fn display_matches_the_compact_format() {
+    let board = VariantBoard::<Size10>::standard();
+    let display = board.to_string();
+    let lines: Vec<&str> = display.lines().collect();
+    assert_eq!(lines[0], "    ABCDEFGHIJ");
+    assert_eq!(lines[1], "   +----------+");
+    assert_eq!(lines[6], " 5 |....WB....|");
+    assert_eq!(lines[11], "10 |..........|");
+
+    let board = VariantBoard::<Size6>::standard();
+    assert!(board.to_string().starts_with("   ABCDEF\n  +------+\n1 |"));
+}
+
+#[test]
+fn coords_roundtrip() {
+    for text in ["a1", "f6", "j10"] {
+        let coord: Coord = text.parse().unwrap();
+        assert_eq!(coord.to_string(), text);
+    }
+    for text in ["", "a", "a0", "1a", "a+1", "aa1"] {
+        assert!(text.parse::<Coord>().is_err(), "{text}");
+    }
+    assert_eq!(Size6::bit("g1".parse().unwrap()), None);
+    assert_eq!(Coord::new(200, 0).to_string(), "?1");
+    assert_eq!(Coord::new(25, 254).to_string(), "z255");
+}